@@ -33,7 +33,8 @@ mod sealed {
     /// if the feature `allocator_api` is not enabled.
     ///
     /// This type only exists as a placeholder, and will not be constructed.
-    #[allow(missing_copy_implementations, missing_debug_implementations)]
+    #[allow(missing_copy_implementations)]
+    #[derive(Debug, Clone)]
     pub struct DummyAllocator;
     impl Sealed for DummyAllocator {}
     impl Allocator for DummyAllocator {}
@@ -2,7 +2,7 @@
 
 use core::{marker::PhantomData, mem};
 
-use alloc::vec::Vec;
+use alloc::{collections::TryReserveError, vec::Vec};
 
 use crate::allocator::{Allocator, DefaultAllocator};
 
@@ -28,16 +28,155 @@ impl<'a, K, V, A: Allocator> Entry<'a, K, V, A> {
         }
     }
 
+    /// Get a reference to the value, if the entry is occupied.
+    #[must_use]
+    #[inline]
+    pub fn get(&self) -> Option<&V> {
+        match self {
+            Entry::Occupied(occupied) => Some(occupied.get()),
+            Entry::Vacant(_vacant) => None,
+        }
+    }
+
+    /// Get a mutable reference to the value, if the entry is occupied.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut V> {
+        match self {
+            Entry::Occupied(occupied) => Some(occupied.get_mut()),
+            Entry::Vacant(_vacant) => None,
+        }
+    }
+
     /// Ensures a value is in the entry by inserting the default if empty,
     /// and returns a mutable reference to the value in the entry.
     #[must_use]
     #[inline]
     pub fn or_insert(self, default: V) -> &'a mut V {
         match self {
-            Entry::Occupied(occupied) => occupied.get_mut(),
+            Entry::Occupied(occupied) => occupied.into_mut(),
             Entry::Vacant(vacant) => vacant.insert(default),
         }
     }
+
+    /// Ensures a value is in the entry by inserting the result of the `default` function if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// The `default` function is only called if the entry is vacant.
+    #[must_use]
+    #[inline]
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(occupied) => occupied.into_mut(),
+            Entry::Vacant(vacant) => vacant.insert(default()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// In contrast to [`or_insert`](Entry::or_insert), an allocation failure is reported
+    /// instead of aborting. An occupied entry never allocates.
+    ///
+    /// ## Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    #[inline]
+    pub fn or_try_insert(self, default: V) -> Result<&'a mut V, TryReserveError> {
+        self.or_try_insert_with(|| default)
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the `default` function if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// In contrast to [`or_insert_with`](Entry::or_insert_with), an allocation failure is reported
+    /// instead of aborting. An occupied entry never allocates.
+    /// The `default` function is only called if the entry is vacant and the allocation succeeded.
+    ///
+    /// ## Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    #[inline]
+    pub fn or_try_insert_with(
+        self,
+        default: impl FnOnce() -> V,
+    ) -> Result<&'a mut V, TryReserveError> {
+        match self {
+            Entry::Occupied(occupied) => Ok(occupied.into_mut()),
+            Entry::Vacant(vacant) => {
+                vacant.vec.try_reserve(1)?;
+                Ok(vacant.insert(default()))
+            },
+        }
+    }
+
+    /// Sets the value of the entry, and returns an [`OccupiedEntry`] for it.
+    ///
+    /// If the entry is vacant, the `key` is moved into the [`AssocList`](crate::AssocList),
+    /// see [`VacantEntry::insert_entry`].
+    /// Only if the entry was occupied, the returned [`OccupiedEntry`] keeps the `key` used to create
+    /// the [`Entry`], so e.g. [`OccupiedEntry::into_key`] returns [`None`] otherwise.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, A> {
+        match self {
+            Entry::Occupied(mut occupied) => {
+                let _ = occupied.insert(value);
+                occupied
+            },
+            Entry::Vacant(vacant) => vacant.insert_entry(value),
+        }
+    }
+}
+
+/// A view into an [`AssocList`](crate::AssocList) for the first element matching a predicate.
+/// It is created by the [`entry_by`](crate::AssocList::entry_by)-method.
+///
+/// In contrast to an [`Entry`], no separate key is available for an occupied element.
+#[derive(Debug)]
+#[must_use]
+pub enum RawEntry<'a, K, V, A: Allocator = DefaultAllocator> {
+    /// The [`AssocList`](crate::AssocList) contains an element matching the predicate.
+    Occupied(IndexedEntry<'a, K, V, A>),
+    /// The [`AssocList`](crate::AssocList) doesn't contain an element matching the predicate.
+    Vacant(VacantEntry<'a, K, V, A>),
+}
+
+impl<'a, K, V, A: Allocator> RawEntry<'a, K, V, A> {
+    /// Return the key stored in the [`AssocList`](crate::AssocList) if the entry is occupied,
+    /// or the constructed key if it is vacant.
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            RawEntry::Occupied(occupied) => occupied.key(),
+            RawEntry::Vacant(vacant) => vacant.key(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default if empty,
+    /// and returns a mutable reference to the value in the entry.
+    #[must_use]
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            RawEntry::Occupied(occupied) => occupied.into_mut(),
+            RawEntry::Vacant(vacant) => vacant.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the `default` function if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// The `default` function is only called if the entry is vacant.
+    #[must_use]
+    #[inline]
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            RawEntry::Occupied(occupied) => occupied.into_mut(),
+            RawEntry::Vacant(vacant) => vacant.insert(default()),
+        }
+    }
 }
 
 // mimicking Entry-API for e.g. BTreeMap
@@ -58,15 +197,84 @@ pub struct OccupiedEntry<'a, K, V, A: Allocator = DefaultAllocator> {
     /// The index of the element.
     pub(crate) index: usize,
     /// The key used to create the [`Entry`].
-    pub(crate) key: K,
+    /// It is [`None`], if the [`OccupiedEntry`] was created without a separate key,
+    /// e.g. by [`VacantEntry::insert_entry`].
+    pub(crate) key: Option<K>,
 }
 
 impl<'a, K, V, A: Allocator> OccupiedEntry<'a, K, V, A> {
     /// Return the `key` used to create the [`Entry`].
+    ///
+    /// If the [`OccupiedEntry`] was created without a separate key,
+    /// e.g. by [`VacantEntry::insert_entry`], the key stored in the
+    /// [`AssocList`](crate::AssocList) is returned instead.
+    /// For the stored key, see [`get_key_value`](OccupiedEntry::get_key_value).
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
     #[must_use]
     #[inline]
     pub fn key(&self) -> &K {
-        &self.key
+        self.key.as_ref().unwrap_or_else(|| {
+            let (stored_key, _value) = self.vec.get(self.index).expect("Index out of bounds!");
+            stored_key
+        })
+    }
+
+    /// Get a reference to the key-value pair stored in the [`AssocList`](crate::AssocList).
+    ///
+    /// In contrast to [`key`](OccupiedEntry::key), this returns the stored key,
+    /// not the `key` used to create the [`Entry`].
+    /// Both are equal according to [`PartialEq`], but might differ otherwise.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn get_key_value(&self) -> (&K, &V) {
+        let (key, value) = self.vec.get(self.index).expect("Index out of bounds!");
+        (key, value)
+    }
+
+    /// Convert the [`OccupiedEntry`] into references to the key-value pair stored in the
+    /// [`AssocList`](crate::AssocList), bound to its lifetime.
+    ///
+    /// In contrast to [`key`](OccupiedEntry::key), this returns the stored key,
+    /// not the `key` used to create the [`Entry`].
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn into_key_value(self) -> (&'a K, &'a mut V) {
+        let (key, value) = self.vec.get_mut(self.index).expect("Index out of bounds!");
+        (key, value)
+    }
+
+    /// Return the position of the element in the [`AssocList`](crate::AssocList).
+    ///
+    /// Note that removing an element changes the position of other elements, e.g.
+    /// [`AssocList::swap_remove`](crate::AssocList::swap_remove) moves the last element
+    /// to the position of the removed one.
+    /// Previously observed indices may therefore become invalid.
+    #[must_use]
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Take ownership of the `key` used to create the [`Entry`].
+    /// The [`AssocList`](crate::AssocList) is not modified.
+    ///
+    /// Returns [`None`], if the [`OccupiedEntry`] was created without a separate key,
+    /// e.g. by [`VacantEntry::insert_entry`].
+    #[must_use]
+    #[inline]
+    pub fn into_key(self) -> Option<K> {
+        self.key
     }
 
     /// Get a reference to the Element contained in the [`AssocList`](crate::AssocList).
@@ -76,46 +284,165 @@ impl<'a, K, V, A: Allocator> OccupiedEntry<'a, K, V, A> {
     /// Programming error: if the index of the [`Entry`] is out-of-bounds.
     #[must_use]
     #[inline]
-    pub fn get(self) -> &'a V {
+    pub fn get(&self) -> &V {
         let (_key, value) = self.vec.get(self.index).expect("Index out of bounds!");
         value
     }
 
+    /// Get a clone of the Element contained in the [`AssocList`](crate::AssocList).
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn get_cloned(&self) -> V
+    where
+        V: Clone,
+    {
+        self.get().clone()
+    }
+
+    /// Get a copy of the Element contained in the [`AssocList`](crate::AssocList).
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn get_copied(&self) -> V
+    where
+        V: Copy,
+    {
+        *self.get()
+    }
+
     /// Get a mutable reference to the Element contained in the [`AssocList`](crate::AssocList).
     ///
+    /// If you need a reference that may outlive the [`OccupiedEntry`],
+    /// see [`into_mut`](OccupiedEntry::into_mut).
+    ///
     /// ## Panics
     ///
     /// Programming error: if the index of the [`Entry`] is out-of-bounds.
     #[must_use]
     #[inline]
-    pub fn get_mut(self) -> &'a mut V {
+    pub fn get_mut(&mut self) -> &mut V {
+        let (_key, value) = self.vec.get_mut(self.index).expect("Index out of bounds!");
+        value
+    }
+
+    /// Convert the [`OccupiedEntry`] into a mutable reference to the Element contained
+    /// in the [`AssocList`](crate::AssocList), bound to its lifetime.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
         let (_key, value) = self.vec.get_mut(self.index).expect("Index out of bounds!");
         value
     }
 
     /// Remove the element from the [`AssocList`](crate::AssocList), returning the key-value pair.
     ///
+    /// The order of the remaining elements is preserved,
+    /// at the cost of shifting all following elements (`O(n)`).
+    ///
     /// ## Panics
     ///
     /// Programming error: if the index of the [`Entry`] is out-of-bounds.
     #[must_use]
     #[inline]
     pub fn remove_entry(self) -> (K, V) {
-        self.vec.swap_remove(self.index)
+        self.vec.remove(self.index)
     }
 
     /// Remove the element from the [`AssocList`](crate::AssocList), returning the value.
     ///
+    /// The order of the remaining elements is preserved,
+    /// at the cost of shifting all following elements (`O(n)`).
+    ///
     /// ## Panics
     ///
     /// Programming error: if the index of the [`Entry`] is out-of-bounds.
     #[must_use]
     #[inline]
     pub fn remove(self) -> V {
+        let (_key, value) = self.vec.remove(self.index);
+        value
+    }
+
+    /// Remove the element from the [`AssocList`](crate::AssocList), returning the key-value pair.
+    ///
+    /// In contrast to [`remove_entry`](OccupiedEntry::remove_entry), the last element is moved
+    /// to the position of the removed one (`O(1)`), so the order is not preserved.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn swap_remove_entry(self) -> (K, V) {
+        self.vec.swap_remove(self.index)
+    }
+
+    /// Remove the element from the [`AssocList`](crate::AssocList), returning the value.
+    ///
+    /// In contrast to [`remove`](OccupiedEntry::remove), the last element is moved
+    /// to the position of the removed one (`O(1)`), so the order is not preserved.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn swap_remove(self) -> V {
         let (_key, value) = self.vec.swap_remove(self.index);
         value
     }
 
+    /// Replace the key stored in the [`AssocList`](crate::AssocList) with the `key` used to create
+    /// the [`Entry`], returning the previously stored key.
+    ///
+    /// ## Errors
+    ///
+    /// If the [`OccupiedEntry`] was created without a separate key,
+    /// e.g. by [`VacantEntry::insert_entry`], it is returned unchanged.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
+    #[inline]
+    pub fn replace_key(self) -> Result<K, Self> {
+        let OccupiedEntry { vec, index, key: Some(key), .. } = self else {
+            return Err(self);
+        };
+        let (stored_key, _value) = vec.get_mut(index).expect("Index out of bounds!");
+        Ok(mem::replace(stored_key, key))
+    }
+
+    /// Replace the key-value pair stored in the [`AssocList`](crate::AssocList) with the `key`
+    /// used to create the [`Entry`] and `value`, returning the previously stored key-value pair.
+    ///
+    /// ## Errors
+    ///
+    /// If the [`OccupiedEntry`] was created without a separate key,
+    /// e.g. by [`VacantEntry::insert_entry`], it is returned unchanged together with `value`.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`Entry`] is out-of-bounds.
+    #[inline]
+    pub fn replace_entry(self, value: V) -> Result<(K, V), (Self, V)> {
+        let OccupiedEntry { vec, index, key: Some(key), .. } = self else {
+            return Err((self, value));
+        };
+        let pair = vec.get_mut(index).expect("Index out of bounds!");
+        Ok(mem::replace(pair, (key, value)))
+    }
+
     /// Replace the element from the [`AssocList`](crate::AssocList), returning the previous value.
     ///
     /// ## Panics
@@ -132,7 +459,7 @@ impl<'a, K, V, A: Allocator> OccupiedEntry<'a, K, V, A> {
 // mimicking Entry-API for e.g. BTreeMap
 #[allow(clippy::module_name_repetitions)]
 /// A view into a vacant entry in an [`AssocList`](crate::AssocList).
-/// It is part of the [`Entry`] enum.
+/// It is part of the [`Entry`] and [`RawEntry`] enums.
 #[derive(Debug)]
 #[must_use]
 pub struct VacantEntry<'a, K, V, A: Allocator = DefaultAllocator> {
@@ -156,6 +483,26 @@ impl<'a, K, V, A: Allocator> VacantEntry<'a, K, V, A> {
         &self.key
     }
 
+    /// Take ownership of the `key` used to create the [`Entry`], without inserting an element.
+    ///
+    /// ```
+    /// use associated_list::{entry::Entry, AssocList};
+    ///
+    /// let mut assoc_list: AssocList<String, u8> = AssocList::new();
+    /// let key = match assoc_list.entry(String::from("vacant")) {
+    ///     Entry::Occupied(occupied) => occupied.into_key(),
+    ///     // decide not to insert a value
+    ///     Entry::Vacant(vacant) => Some(vacant.into_key()),
+    /// };
+    /// assert_eq!(key.as_deref(), Some("vacant"));
+    /// assert!(assoc_list.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
     /// Add a new element associated with the [`key`](VacantEntry::key).
     ///
     /// ## Panics
@@ -168,4 +515,141 @@ impl<'a, K, V, A: Allocator> VacantEntry<'a, K, V, A> {
         let (_key, inserted_value) = self.vec.last_mut().expect("Element has just been added!");
         inserted_value
     }
+
+    /// Add a new element associated with the [`key`](VacantEntry::key),
+    /// and return an [`OccupiedEntry`] for it.
+    ///
+    /// The `key` is moved into the [`AssocList`](crate::AssocList), so the [`OccupiedEntry`]
+    /// refers to the stored key. Consequently, [`OccupiedEntry::into_key`] returns [`None`],
+    /// while [`OccupiedEntry::replace_key`] and [`OccupiedEntry::replace_entry`] return an error.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V, A> {
+        let VacantEntry { vec, phantom, key } = self;
+        let index = vec.len();
+        vec.push((key, value));
+        OccupiedEntry { vec, phantom, index, key: None }
+    }
+}
+
+// mimicking Entry-API for e.g. BTreeMap
+#[allow(clippy::module_name_repetitions)]
+/// A view into an element of an [`AssocList`](crate::AssocList), selected by its position.
+/// It is created by the [`entry_at`](crate::AssocList::entry_at),
+/// [`first_entry`](crate::AssocList::first_entry) and
+/// [`last_entry`](crate::AssocList::last_entry)-methods.
+/// It is part of the [`RawEntry`] enum.
+#[derive(Debug)]
+#[must_use]
+pub struct IndexedEntry<'a, K, V, A: Allocator = DefaultAllocator> {
+    #[cfg(feature = "allocator_api")]
+    /// The vector of the [`AssocList`](crate::AssocList).
+    pub(crate) vec: &'a mut Vec<(K, V), A>,
+    #[cfg(not(feature = "allocator_api"))]
+    /// The vector of the [`AssocList`](crate::AssocList).
+    pub(crate) vec: &'a mut Vec<(K, V)>,
+    /// [`PhantomData`]
+    pub(crate) phantom: PhantomData<A>,
+    /// The index of the element.
+    pub(crate) index: usize,
+}
+
+impl<'a, K, V, A: Allocator> IndexedEntry<'a, K, V, A> {
+    /// Return the position of the element in the [`AssocList`](crate::AssocList).
+    #[must_use]
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Return the key stored in the [`AssocList`](crate::AssocList).
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`IndexedEntry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &K {
+        let (key, _value) = self.vec.get(self.index).expect("Index out of bounds!");
+        key
+    }
+
+    /// Get a reference to the Element contained in the [`AssocList`](crate::AssocList).
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`IndexedEntry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn get(&self) -> &V {
+        let (_key, value) = self.vec.get(self.index).expect("Index out of bounds!");
+        value
+    }
+
+    /// Get a mutable reference to the Element contained in the [`AssocList`](crate::AssocList).
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`IndexedEntry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        let (_key, value) = self.vec.get_mut(self.index).expect("Index out of bounds!");
+        value
+    }
+
+    /// Convert the [`IndexedEntry`] into a mutable reference to the Element contained
+    /// in the [`AssocList`](crate::AssocList), bound to its lifetime.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`IndexedEntry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        let (_key, value) = self.vec.get_mut(self.index).expect("Index out of bounds!");
+        value
+    }
+
+    /// Replace the element from the [`AssocList`](crate::AssocList), returning the previous value.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`IndexedEntry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn insert(&mut self, new_value: V) -> V {
+        let (_key, value) = self.vec.get_mut(self.index).expect("Index out of bounds!");
+        mem::replace(value, new_value)
+    }
+
+    /// Remove the element from the [`AssocList`](crate::AssocList), returning the key-value pair.
+    ///
+    /// The order of the remaining elements is preserved,
+    /// at the cost of shifting all following elements (`O(n)`).
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`IndexedEntry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn remove(self) -> (K, V) {
+        self.vec.remove(self.index)
+    }
+
+    /// Remove the element from the [`AssocList`](crate::AssocList), returning the key-value pair.
+    ///
+    /// In contrast to [`remove`](IndexedEntry::remove), the last element is moved
+    /// to the position of the removed one (`O(1)`), so the order is not preserved.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if the index of the [`IndexedEntry`] is out-of-bounds.
+    #[must_use]
+    #[inline]
+    pub fn swap_remove(self) -> (K, V) {
+        self.vec.swap_remove(self.index)
+    }
 }
@@ -0,0 +1,23 @@
+//! Trait for key lookups with types different from the key type.
+
+use core::borrow::Borrow;
+
+/// Key equivalence used by the lookup methods of an [`AssocList`](crate::AssocList).
+///
+/// Every type `Q` with `K: Borrow<Q>` is equivalent to `K` according to its [`PartialEq`]
+/// implementation. Implement this trait for a custom query type to allow lookups
+/// by composite or normalized keys, e.g. a case-insensitive comparison.
+///
+/// The implementation has to be consistent with the [`PartialEq`] implementation of `K`,
+/// i.e. a query should not be equivalent to two keys that are not equal to each other.
+pub trait Equivalent<K: ?Sized> {
+    /// Is the query `self` equivalent to the `key`.
+    fn equivalent(&self, key: &K) -> bool;
+}
+
+impl<Q: PartialEq + ?Sized, K: Borrow<Q> + ?Sized> Equivalent<K> for Q {
+    #[inline]
+    fn equivalent(&self, key: &K) -> bool {
+        self == key.borrow()
+    }
+}
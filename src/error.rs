@@ -0,0 +1,220 @@
+//! Error types for fallible operations of an [`AssocList`](crate::AssocList).
+//!
+//! All error types implement [`Display`]. The `Error`-trait is only implemented
+//! with the "std" feature, since it is only available in `core` starting with Rust 1.81,
+//! while the minimum supported Rust version of this crate is 1.75.
+//! Once the minimum supported Rust version reaches 1.81, `core::error::Error`
+//! will be implemented unconditionally.
+
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use alloc::collections::TryReserveError;
+
+use crate::{
+    allocator::{Allocator, DefaultAllocator},
+    entry::OccupiedEntry,
+};
+
+// mimicking naming of e.g. TryReserveError
+#[allow(clippy::module_name_repetitions)]
+/// The error returned when a key occurs multiple times, although unique keys are required.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKeyError<K> {
+    /// The duplicated key.
+    pub(crate) key: K,
+    /// The index of the duplicated key in the input.
+    pub(crate) index: usize,
+    /// The index of the first occurrence of the key.
+    pub(crate) first_index: usize,
+}
+
+impl<K> DuplicateKeyError<K> {
+    /// Return the duplicated key.
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Return the index in the input, where the key occurred again.
+    ///
+    /// For [`extend_unique`](crate::AssocList::extend_unique), this is the number of
+    /// key-value pairs inserted before the error occurred.
+    #[must_use]
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Return the index, where the key occurred first.
+    ///
+    /// For [`extend_unique`](crate::AssocList::extend_unique), this is the position of the key
+    /// in the [`AssocList`](crate::AssocList), otherwise the index in the input.
+    #[must_use]
+    #[inline]
+    pub fn first_index(&self) -> usize {
+        self.first_index
+    }
+
+    /// Return the duplicated key, consuming the error.
+    #[must_use]
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<K> Display for DuplicateKeyError<K> {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        let DuplicateKeyError { key: _, index, first_index } = self;
+        write!(formatter, "duplicate key at index {index}, first occurrence at index {first_index}")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Debug> Error for DuplicateKeyError<K> {}
+
+/// The error returned by [`compare_and_swap`](crate::AssocList::compare_and_swap),
+/// containing the rejected new value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CasError<V> {
+    /// The [`AssocList`](crate::AssocList) doesn't contain a value for the key.
+    MissingKey(V),
+    /// The current value associated with the key doesn't match the expected value.
+    Mismatch(V),
+}
+
+impl<V> CasError<V> {
+    /// Return the rejected new value, consuming the error.
+    #[must_use]
+    #[inline]
+    pub fn into_value(self) -> V {
+        match self {
+            CasError::MissingKey(value) | CasError::Mismatch(value) => value,
+        }
+    }
+}
+
+impl<V> Display for CasError<V> {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CasError::MissingKey(_value) => {
+                write!(formatter, "no value is associated with the key")
+            },
+            CasError::Mismatch(_value) => {
+                write!(formatter, "the current value doesn't match the expected value")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: Debug> Error for CasError<V> {}
+
+/// The error returned by [`try_insert`](crate::AssocList::try_insert)
+/// if the [`AssocList`](crate::AssocList) already contains the key.
+///
+/// Contains the [`OccupiedEntry`] for the key and the rejected value.
+// mimicking naming of e.g. TryReserveError
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct OccupiedError<'a, K, V, A: Allocator = DefaultAllocator> {
+    /// The entry of the already contained key.
+    pub(crate) entry: OccupiedEntry<'a, K, V, A>,
+    /// The rejected value.
+    pub(crate) value: V,
+}
+
+impl<'a, K, V, A: Allocator> OccupiedError<'a, K, V, A> {
+    /// Return the [`OccupiedEntry`] of the already contained key.
+    #[inline]
+    pub fn entry(&self) -> &OccupiedEntry<'a, K, V, A> {
+        &self.entry
+    }
+
+    /// Return the rejected value.
+    #[must_use]
+    #[inline]
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// Return the [`OccupiedEntry`] and the rejected value, consuming the error.
+    #[inline]
+    pub fn into_parts(self) -> (OccupiedEntry<'a, K, V, A>, V) {
+        (self.entry, self.value)
+    }
+}
+
+impl<K, V, A: Allocator> Display for OccupiedError<'_, K, V, A> {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "key already exists at index {}", self.entry.index())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Debug, V: Debug, A: Allocator + Debug> Error for OccupiedError<'_, K, V, A> {}
+
+/// A unified error for fallible insertions, either caused by a duplicate key
+/// or by an allocation failure.
+///
+/// Both [`DuplicateKeyError`] and [`TryReserveError`] convert into an [`InsertError`],
+/// so the `?`-operator can be used for both.
+///
+/// ```rust
+/// use associated_list::{error::InsertError, AssocList};
+///
+/// fn parse(pairs: Vec<(u8, char)>) -> Result<AssocList<u8, char>, InsertError<u8>> {
+///     let mut assoc_list = AssocList::try_from(pairs)?;
+///     assoc_list.try_reserve(1)?;
+///     let _ = assoc_list.insert(0, 'x');
+///     Ok(assoc_list)
+/// }
+///
+/// assert!(parse(Vec::from([(1, 'a'), (2, 'b')])).is_ok());
+/// assert!(matches!(
+///     parse(Vec::from([(1, 'a'), (1, 'b')])),
+///     Err(InsertError::DuplicateKey(_))
+/// ));
+/// ```
+// mimicking naming of e.g. TryReserveError
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertError<K> {
+    /// A key occurred multiple times.
+    DuplicateKey(DuplicateKeyError<K>),
+    /// Reserving the required capacity failed.
+    Reserve(TryReserveError),
+}
+
+impl<K> From<DuplicateKeyError<K>> for InsertError<K> {
+    #[inline]
+    fn from(error: DuplicateKeyError<K>) -> Self {
+        InsertError::DuplicateKey(error)
+    }
+}
+
+impl<K> From<TryReserveError> for InsertError<K> {
+    #[inline]
+    fn from(error: TryReserveError) -> Self {
+        InsertError::Reserve(error)
+    }
+}
+
+impl<K> Display for InsertError<K> {
+    #[inline]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InsertError::DuplicateKey(error) => Display::fmt(error, formatter),
+            InsertError::Reserve(error) => Display::fmt(error, formatter),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Debug> Error for InsertError<K> {}
@@ -7,7 +7,7 @@ use core::{
 
 use alloc::vec::{self, IntoIter};
 
-use crate::allocator::Allocator;
+use crate::{allocator::Allocator, equivalent::Equivalent};
 
 /// Draining Iterator for an [`AssocList`](crate::AssocList).
 /// It is created by the [`drain`](crate::AssocList::drain)-method.
@@ -142,3 +142,37 @@ impl<K, V, A: Allocator> Iterator for IntoValues<K, V, A> {
         self.iter.next().map(|(_key, value)| value)
     }
 }
+
+/// Iterator looking up the values associated with several keys of an
+/// [`AssocList`](crate::AssocList).
+/// It is created by the [`get_many`](crate::AssocList::get_many)-method.
+#[derive(Debug)]
+#[must_use]
+pub struct GetMany<'a, K, V, I> {
+    /// The key-value pairs of the [`AssocList`](crate::AssocList).
+    pub(crate) pairs: &'a [(K, V)],
+    /// The queried keys.
+    pub(crate) keys: I,
+}
+
+impl<'a, 'q, K, V, Q, I> Iterator for GetMany<'a, K, V, I>
+where
+    Q: Equivalent<K> + ?Sized + 'q,
+    I: Iterator<Item = &'q Q>,
+{
+    type Item = Option<&'a V>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        let value = self.pairs.iter().find_map(|(contained_key, contained_value)| {
+            key.equivalent(contained_key).then_some(contained_value)
+        });
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
@@ -5,14 +5,18 @@
 
 use core::{
     borrow::Borrow,
+    cmp::Ordering,
     fmt::{self, Debug, Formatter},
+    iter::Sum,
     marker::PhantomData,
     mem,
-    ops::{Index, IndexMut},
+    ops::{BitAnd, BitOr, Index, IndexMut, RangeBounds, Sub},
     slice::Iter,
 };
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 use alloc::{
     collections::TryReserveError,
     vec::{IntoIter, Vec},
@@ -20,14 +24,18 @@ use alloc::{
 
 pub mod allocator;
 pub mod entry;
+pub mod equivalent;
+pub mod error;
 pub mod iter;
 #[cfg(test)]
 mod test;
 
 use self::{
     allocator::{Allocator, DefaultAllocator},
-    entry::{Entry, OccupiedEntry, VacantEntry},
-    iter::{Drain, IntoKeys, IntoValues, IterMut, Keys, Values, ValuesMut},
+    entry::{Entry, IndexedEntry, OccupiedEntry, RawEntry, VacantEntry},
+    equivalent::Equivalent,
+    error::{CasError, DuplicateKeyError, OccupiedError},
+    iter::{Drain, GetMany, IntoKeys, IntoValues, IterMut, Keys, Values, ValuesMut},
 };
 
 /// An associated list based on a [`Vec`], providing the usual map functionality.
@@ -75,6 +83,51 @@ impl<K, V> AssocList<K, V> {
     pub fn with_capacity(capacity: usize) -> Self {
         AssocList { vec: Vec::with_capacity(capacity), phantom: PhantomData }
     }
+
+    /// Create a new [`AssocList`] with at least the specified `capacity`.
+    ///
+    /// ## Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut assoc_list = AssocList::new();
+        assoc_list.try_reserve_exact(capacity)?;
+        Ok(assoc_list)
+    }
+
+    /// Create a new [`AssocList`] from the key-value pairs of `iter`,
+    /// like [`collect`](Iterator::collect),
+    /// but report allocation failures instead of panicking or aborting.
+    /// For duplicate keys, the later value is kept.
+    ///
+    /// ## Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, TryReserveError>
+    where
+        K: PartialEq,
+    {
+        let mut assoc_list = AssocList::new();
+        assoc_list.try_extend(iter)?;
+        Ok(assoc_list)
+    }
+
+    /// Create a new [`AssocList`] from an array, rejecting duplicate keys.
+    ///
+    /// A `TryFrom<[(K, V); N]>`-implementation would conflict with the blanket implementation
+    /// based on the [`From`]-implementation for arrays, so this method is provided instead.
+    /// It is equivalent to `AssocList::try_from(Vec::from(array))`.
+    ///
+    /// ## Errors
+    /// Returns a [`DuplicateKeyError`] for the first element with a key, that already occurred
+    /// at a smaller index. The array is consumed, all other key-value pairs are dropped.
+    #[inline]
+    pub fn try_from_array<const N: usize>(array: [(K, V); N]) -> Result<Self, DuplicateKeyError<K>>
+    where
+        K: PartialEq,
+    {
+        AssocList::try_from(Vec::from(array))
+    }
 }
 
 /// Create a new [`AssocList`], filled with the arguments.
@@ -120,6 +173,17 @@ impl<K, V, A: Allocator> AssocList<K, V, A> {
     pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
         AssocList { vec: Vec::with_capacity_in(capacity, alloc), phantom: PhantomData }
     }
+
+    /// Create a new [`AssocList`] with at least the specified `capacity` with the provided allocator.
+    ///
+    /// ## Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    #[inline]
+    pub fn try_with_capacity_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
+        let mut assoc_list = AssocList::new_in(alloc);
+        assoc_list.try_reserve_exact(capacity)?;
+        Ok(assoc_list)
+    }
 }
 
 impl<K, V, A: Allocator> AssocList<K, V, A> {
@@ -165,15 +229,23 @@ impl<K, V, A: Allocator> AssocList<K, V, A> {
         IterMut(self.vec.iter_mut())
     }
 
-    /// Removes all key-value pairs from the [`AssocList`] in bulk, returning all removed elements as an iterator.
+    /// Removes the key-value pairs with positions within `range` from the [`AssocList`] in bulk,
+    /// returning all removed elements as an iterator.
     /// If the iterator is dropped before being fully consumed, it drops the remaining removed elements.
+    /// The remaining elements keep their relative order.
+    ///
+    /// Use `drain(..)` to remove all key-value pairs.
+    ///
+    /// ## Panics
+    /// Panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the [`AssocList`].
     ///
     /// ## Leaking
     ///
     /// See [`Vec::drain`].
     #[inline]
-    pub fn drain(&mut self) -> Drain<'_, K, V, A> {
-        Drain { iter: self.vec.drain(..), phantom: self.phantom }
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, K, V, A> {
+        Drain { iter: self.vec.drain(range), phantom: self.phantom }
     }
 
     /// Return the number of key-value pairs currently contained in the [`AssocList`].
@@ -214,7 +286,7 @@ impl<K, V, A: Allocator> AssocList<K, V, A> {
                 return Entry::Occupied(OccupiedEntry {
                     vec: &mut self.vec,
                     phantom: self.phantom,
-                    key,
+                    key: Some(key),
                     index,
                 });
             }
@@ -222,70 +294,419 @@ impl<K, V, A: Allocator> AssocList<K, V, A> {
         Entry::Vacant(VacantEntry { vec: &mut self.vec, phantom: self.phantom, key })
     }
 
+    /// Get the [`RawEntry`] for the first key matching the predicate `find`.
+    ///
+    /// The key for a [`VacantEntry`] is only constructed by calling `make_key`
+    /// if no key matches. Otherwise, the [`IndexedEntry`] refers to the stored key.
+    ///
+    /// `find` should only match keys equal to the result of `make_key`,
+    /// otherwise inserting into the [`VacantEntry`] might result in duplicate keys.
+    #[inline]
+    pub fn entry_by(
+        &mut self,
+        mut find: impl FnMut(&K) -> bool,
+        make_key: impl FnOnce() -> K,
+    ) -> RawEntry<'_, K, V, A> {
+        if let Some(index) = self.vec.iter().position(|(key, _value)| find(key)) {
+            return RawEntry::Occupied(IndexedEntry {
+                vec: &mut self.vec,
+                phantom: self.phantom,
+                index,
+            });
+        }
+        RawEntry::Vacant(VacantEntry { vec: &mut self.vec, phantom: self.phantom, key: make_key() })
+    }
+
+    /// Get the [`IndexedEntry`] for the key-value pair at position `index`,
+    /// or [`None`] if `index` is out of bounds.
+    #[inline]
+    pub fn entry_at(&mut self, index: usize) -> Option<IndexedEntry<'_, K, V, A>> {
+        (index < self.len()).then_some(IndexedEntry {
+            vec: &mut self.vec,
+            phantom: self.phantom,
+            index,
+        })
+    }
+
+    /// Get the [`IndexedEntry`] for the first key-value pair in insertion order,
+    /// or [`None`] if the [`AssocList`] is empty.
+    #[inline]
+    pub fn first_entry(&mut self) -> Option<IndexedEntry<'_, K, V, A>> {
+        self.entry_at(0)
+    }
+
+    /// Get the [`IndexedEntry`] for the last key-value pair in insertion order,
+    /// or [`None`] if the [`AssocList`] is empty.
+    #[inline]
+    pub fn last_entry(&mut self) -> Option<IndexedEntry<'_, K, V, A>> {
+        let index = self.len().checked_sub(1)?;
+        self.entry_at(index)
+    }
+
     /// Does the [`AssocList`] contain a value associated with the `key`.
     #[must_use]
     #[inline]
     pub fn contains_key<Q>(&self, key: &Q) -> bool
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
         for (contained_key, _contained_value) in &self.vec {
-            if contained_key.borrow() == key {
+            if key.equivalent(contained_key) {
                 return true;
             }
         }
         false
     }
 
+    /// Does the [`AssocList`] contain the `key`, associated with a value equal to `value`.
+    #[must_use]
+    #[inline]
+    pub fn contains_entry<Q, W>(&self, key: &Q, value: &W) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+        V: PartialEq<W>,
+        W: ?Sized,
+    {
+        self.get(key).is_some_and(|contained_value| contained_value == value)
+    }
+
     /// Get a reference to the value associated with the `key`.
     #[must_use]
     #[inline]
     pub fn get<Q>(&self, key: &Q) -> Option<&V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
         for (contained_key, contained_value) in &self.vec {
-            if contained_key.borrow() == key {
+            if key.equivalent(contained_key) {
                 return Some(contained_value);
             }
         }
         None
     }
 
+    /// Get a clone of the value associated with the `key`.
+    #[must_use]
+    #[inline]
+    pub fn get_cloned<Q>(&self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+        V: Clone,
+    {
+        self.get(key).cloned()
+    }
+
+    /// Get a copy of the value associated with the `key`.
+    #[must_use]
+    #[inline]
+    pub fn get_copied<Q>(&self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+        V: Copy,
+    {
+        self.get(key).copied()
+    }
+
+    /// Look up the values associated with each of the `keys`.
+    ///
+    /// Yields one result per queried key, in the order of the `keys`.
+    /// Duplicated keys are looked up again, missing keys yield [`None`].
+    /// The yielded references are only bound to the lifetime of the [`AssocList`],
+    /// so they may outlive the `keys`.
+    #[inline]
+    pub fn get_many<'q, Q, I>(&self, keys: I) -> GetMany<'_, K, V, I::IntoIter>
+    where
+        Q: Equivalent<K> + ?Sized + 'q,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        GetMany { pairs: &self.vec, keys: keys.into_iter() }
+    }
+
     /// Get a reference to the key-value pair inside the [`AssocList`] associated with the `key`.
     #[must_use]
     #[inline]
     pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
         for (contained_key, contained_value) in &self.vec {
-            if contained_key.borrow() == key {
+            if key.equivalent(contained_key) {
                 return Some((contained_key, contained_value));
             }
         }
         None
     }
 
+    /// Get the first key-value pair in insertion order, for which `predicate` returns `true`.
+    #[must_use]
+    #[inline]
+    pub fn find(&self, mut predicate: impl FnMut(&K, &V) -> bool) -> Option<(&K, &V)> {
+        let (key, value) = self.vec.iter().find(|(key, value)| predicate(key, value))?;
+        Some((key, value))
+    }
+
+    /// Get the key and mutable access to the value of the first key-value pair in insertion order,
+    /// for which `predicate` returns `true`.
+    #[must_use]
+    #[inline]
+    pub fn find_mut(&mut self, mut predicate: impl FnMut(&K, &V) -> bool) -> Option<(&K, &mut V)> {
+        let (key, value) = self.vec.iter_mut().find(|(key, value)| predicate(key, value))?;
+        Some((key, value))
+    }
+
+    /// Apply `function` to the key-value pairs in insertion order,
+    /// returning the first non-[`None`] result.
+    #[must_use]
+    #[inline]
+    pub fn find_map<T>(&self, mut function: impl FnMut(&K, &V) -> Option<T>) -> Option<T> {
+        self.vec.iter().find_map(|(key, value)| function(key, value))
+    }
+
+    /// Apply `function` to the keys and mutable values in insertion order,
+    /// returning the first non-[`None`] result.
+    #[inline]
+    pub fn find_map_mut<'s, T>(
+        &'s mut self,
+        mut function: impl FnMut(&'s K, &'s mut V) -> Option<T>,
+    ) -> Option<T> {
+        self.vec.iter_mut().find_map(|(key, value)| function(key, value))
+    }
+
+    /// Iterate over the key-value pairs with a key inside of `range`, in insertion order.
+    ///
+    /// In contrast to [`BTreeMap::range`](alloc::collections::BTreeMap::range),
+    /// all elements are checked, so this has a runtime characteristic of `O(n)`.
+    /// Keys not comparable with the bounds of the `range` (e.g. [`f32::NAN`]) are skipped.
+    ///
+    /// ## Example
+    /// ```
+    /// # use associated_list::AssocList;
+    /// let events = AssocList::from([(3, "c"), (1, "a"), (4, "d"), (2, "b")]);
+    /// let window: Vec<_> = events.range(2..4).collect();
+    /// assert_eq!(window, [(&3, &"c"), (&2, &"b")]);
+    /// ```
+    #[inline]
+    pub fn range<R>(&self, range: R) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: PartialOrd,
+        R: RangeBounds<K>,
+    {
+        self.vec
+            .iter()
+            .filter(move |(key, _value)| range.contains(key))
+            .map(|(key, value)| (key, value))
+    }
+
+    /// Iterate over the keys and mutable values with a key inside of `range`, in insertion order.
+    ///
+    /// See [`range`](AssocList::range) for details.
+    #[inline]
+    pub fn range_mut<R>(&mut self, range: R) -> impl Iterator<Item = (&K, &mut V)>
+    where
+        K: PartialOrd,
+        R: RangeBounds<K>,
+    {
+        self.vec
+            .iter_mut()
+            .filter(move |(key, _value)| range.contains(key))
+            .map(|(key, value)| (&*key, value))
+    }
+
+    /// Iterate over the key-value pairs with a key starting with `prefix`, in insertion order.
+    ///
+    /// All elements are checked, so this has a runtime characteristic of `O(n)`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use associated_list::AssocList;
+    /// let config = AssocList::from([("net.tcp.timeout", 30), ("log.level", 2), ("net.udp", 5)]);
+    /// let net: Vec<_> = config.iter_prefix("net.").collect();
+    /// assert_eq!(net, [(&"net.tcp.timeout", &30), (&"net.udp", &5)]);
+    /// ```
+    #[inline]
+    pub fn iter_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: Borrow<str>,
+    {
+        self.vec
+            .iter()
+            .filter(move |(key, _value)| key.borrow().starts_with(prefix))
+            .map(|(key, value)| (key, value))
+    }
+
+    /// Iterate over the key-value pairs with a key starting with the slice `prefix`,
+    /// in insertion order.
+    ///
+    /// All elements are checked, so this has a runtime characteristic of `O(n)`.
+    #[inline]
+    pub fn iter_slice_prefix<'a, T: PartialEq>(
+        &'a self,
+        prefix: &'a [T],
+    ) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: Borrow<[T]>,
+    {
+        self.vec
+            .iter()
+            .filter(move |(key, _value)| key.borrow().starts_with(prefix))
+            .map(|(key, value)| (key, value))
+    }
+
     /// Get mutable access to the value associated with the `key`.
     #[must_use]
     #[inline]
     pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
         for (contained_key, contained_value) in &mut self.vec {
-            if Borrow::<Q>::borrow(contained_key) == key {
+            if key.equivalent(contained_key) {
                 return Some(contained_value);
             }
         }
         None
     }
 
+    /// Get mutable access to the values associated with several `keys` at once.
+    ///
+    /// For each key, the result contains the associated value at the same position,
+    /// or [`None`] if the [`AssocList`] doesn't contain the key.
+    ///
+    /// ## Panics
+    /// Panics if several keys resolve to the same element of the [`AssocList`],
+    /// e.g. if a contained key is requested multiple times.
+    /// Keys not contained in the [`AssocList`] may be requested repeatedly,
+    /// each resulting in [`None`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use associated_list::AssocList;
+    /// let mut accounts = AssocList::from([("alice", 100), ("bob", 50)]);
+    /// if let [Some(from), Some(to)] = accounts.get_disjoint_mut(["alice", "bob"]) {
+    ///     *from -= 30;
+    ///     *to += 30;
+    /// }
+    /// assert_eq!(accounts.get("alice"), Some(&70));
+    /// assert_eq!(accounts.get("bob"), Some(&80));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn get_disjoint_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [Option<&mut V>; N]
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let indices = keys.map(|key| self.get_index_of(key));
+        for (position, index) in indices.iter().enumerate() {
+            assert!(
+                index.is_none() || !indices.iter().take(position).any(|previous| previous == index),
+                "get_disjoint_mut called with overlapping keys"
+            );
+        }
+        let mut result = [(); N].map(|()| None);
+        for (index, (_contained_key, contained_value)) in self.vec.iter_mut().enumerate() {
+            let requested = indices.iter().position(|requested| *requested == Some(index));
+            if let Some(slot) = requested.and_then(|position| result.get_mut(position)) {
+                *slot = Some(contained_value);
+            }
+        }
+        result
+    }
+
+    /// Get the position of the key-value pair associated with the `key`.
+    ///
+    /// Note that removing an element changes the position of other elements.
+    #[must_use]
+    #[inline]
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.vec.iter().position(|(contained_key, _contained_value)| key.equivalent(contained_key))
+    }
+
+    /// Get a reference to the key-value pair at position `index`,
+    /// or [`None`] if `index` is out of bounds.
+    #[must_use]
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        let (key, value) = self.vec.get(index)?;
+        Some((key, value))
+    }
+
+    /// Get the position and a reference to the key-value pair associated with the `key`.
+    #[must_use]
+    #[inline]
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        for (index, (contained_key, contained_value)) in self.vec.iter().enumerate() {
+            if key.equivalent(contained_key) {
+                return Some((index, contained_key, contained_value));
+            }
+        }
+        None
+    }
+
+    /// Get the position, the key and mutable access to the value associated with the `key`.
+    #[must_use]
+    #[inline]
+    pub fn get_full_mut<Q>(&mut self, key: &Q) -> Option<(usize, &K, &mut V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        for (index, (contained_key, contained_value)) in self.vec.iter_mut().enumerate() {
+            if key.equivalent(contained_key) {
+                return Some((index, contained_key, contained_value));
+            }
+        }
+        None
+    }
+
+    /// Get the key-value pairs with positions within `range` as a slice,
+    /// or [`None`] if the `range` is out of bounds or its start is greater than its end.
+    #[must_use]
+    #[inline]
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> Option<&[(K, V)]> {
+        self.vec.get((range.start_bound().cloned(), range.end_bound().cloned()))
+    }
+
+    /// Get a reference to the first key-value pair in insertion order,
+    /// or [`None`] if the [`AssocList`] is empty.
+    #[must_use]
+    #[inline]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let (key, value) = self.vec.first()?;
+        Some((key, value))
+    }
+
+    /// Get the key and mutable access to the value of the first key-value pair in insertion order,
+    /// or [`None`] if the [`AssocList`] is empty.
+    #[must_use]
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<(&K, &mut V)> {
+        let (key, value) = self.vec.first_mut()?;
+        Some((key, value))
+    }
+
+    /// Get a reference to the last key-value pair in insertion order,
+    /// or [`None`] if the [`AssocList`] is empty.
+    #[must_use]
+    #[inline]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let (key, value) = self.vec.last()?;
+        Some((key, value))
+    }
+
+    /// Get the key and mutable access to the value of the last key-value pair in insertion order,
+    /// or [`None`] if the [`AssocList`] is empty.
+    #[must_use]
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<(&K, &mut V)> {
+        let (key, value) = self.vec.last_mut()?;
+        Some((key, value))
+    }
+
     /// Insert a new element for the given `key`.
     /// If the [`AssocList`] already contains an element associated with the key, it is replaced and returned.
     ///
@@ -307,120 +728,2147 @@ impl<K, V, A: Allocator> AssocList<K, V, A> {
         None
     }
 
-    /// Remove the element associated with the `key` from the [`AssocList`] and return it.
-    #[must_use]
+    /// Insert a new element for the given `key`, if the [`AssocList`] doesn't contain it already.
+    /// Returns a mutable reference to the inserted value.
+    ///
+    /// ## Errors
+    /// If the [`AssocList`] already contains the key, nothing is updated
+    /// and an [`OccupiedError`] containing the [`OccupiedEntry`] and the rejected value
+    /// is returned.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
     #[inline]
-    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, K, V, A>>
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        K: PartialEq,
     {
-        for (index, (enthaltener_key, _enthaltener_value)) in self.vec.iter().enumerate() {
-            if enthaltener_key.borrow() == key {
-                let (_old_key, old_value) = self.vec.swap_remove(index);
-                return Some(old_value);
-            }
+        match self.entry(key) {
+            Entry::Occupied(entry) => Err(OccupiedError { entry, value }),
+            Entry::Vacant(entry) => Ok(entry.insert(value)),
         }
-        None
     }
 
-    /// Remove the key-value pair associated with the `key` from the [`AssocList`] and return it.
+    /// Insert a new element for the given `key`, returning its position.
+    /// If the [`AssocList`] already contains an element associated with the key,
+    /// it is replaced and returned, and the element keeps its position.
+    /// Otherwise, the element is appended at the end.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
     #[must_use]
     #[inline]
-    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>)
     where
-        K: Borrow<Q>,
-        Q: PartialEq + ?Sized,
+        K: PartialEq,
     {
-        for (index, (enthaltener_key, _enthaltener_value)) in self.vec.iter().enumerate() {
-            if enthaltener_key.borrow() == key {
-                let old_pair = self.vec.swap_remove(index);
-                return Some(old_pair);
+        for (index, (contained_key, contained_value)) in self.vec.iter_mut().enumerate() {
+            if contained_key == &key {
+                let bisher = mem::replace(contained_value, value);
+                return (index, Some(bisher));
             }
         }
-        None
+        let index = self.len();
+        self.vec.push((key, value));
+        (index, None)
     }
 
-    /// Reserves capacity for at least `additional` more key-value pairs to be inserted
-    /// in the given [`AssocList`].
-    /// The collection may reserve more space to speculatively avoid frequent reallocations.
-    /// After calling reserve, capacity will be greater than or equal to `self.len() + additional`.
-    /// Does nothing if capacity is already sufficient.
+    /// Insert a new element for the given `key` at position `index`,
+    /// shifting all following elements to the right.
+    /// If the [`AssocList`] already contains an element associated with the key,
+    /// it is replaced and returned, and the element is moved to position `index`,
+    /// shifting all elements in between.
     ///
     /// ## Panics
+    /// Panics if `index` is greater than the [`AssocList`]'s length,
+    /// or if the key is already contained and `index` is not smaller than the length.
+    ///
     /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
     #[inline]
-    pub fn reserve(&mut self, additional: usize) {
-        self.vec.reserve(additional);
+    pub fn shift_insert(&mut self, index: usize, key: K, value: V) -> Option<V>
+    where
+        K: PartialEq,
+    {
+        let len = self.len();
+        for (current_index, (contained_key, contained_value)) in self.vec.iter_mut().enumerate() {
+            if contained_key == &key {
+                assert!(index < len, "shift_insert index (is {index}) should be < len (is {len})");
+                let bisher = mem::replace(contained_value, value);
+                self.shift_element(current_index, index);
+                return Some(bisher);
+            }
+        }
+        self.vec.insert(index, (key, value));
+        None
     }
 
-    /// Reserves the minimum capacity for at least `additional` more key-value pairs to be inserted
-    /// in the given [`AssocList`].
-    /// Unlike [`reserve`](AssocList::reserve), this will not deliberately over-allocate
-    /// to speculatively avoid frequent allocations. After calling `reserve_exact`,
-    /// capacity will be greater than or equal to `self.len() + additional`.
-    /// Does nothing if the capacity is already sufficient.
+    /// Insert a new element for the given `key` directly before the element
+    /// associated with `anchor`.
+    /// If the [`AssocList`] already contains an element associated with the key,
+    /// it is replaced and returned, and the element is moved directly before `anchor`.
+    /// If `key` is equal to `anchor`, the value is replaced in place.
     ///
-    /// Note that the allocator may give the collection more space than it requests.
-    /// Therefore, capacity can not be relied upon to be precisely minimal.
-    /// Prefer [`reserve`](AssocList::reserve) if future insertions are expected.
+    /// ## Errors
+    /// If the [`AssocList`] doesn't contain an element associated with `anchor`,
+    /// the key-value pair is returned unchanged.
     ///
     /// ## Panics
     /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
     #[inline]
-    pub fn reserve_exact(&mut self, additional: usize) {
-        self.vec.reserve_exact(additional);
+    pub fn insert_before<Q>(&mut self, anchor: &Q, key: K, value: V) -> Result<Option<V>, (K, V)>
+    where
+        K: PartialEq,
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.insert_next_to(anchor, key, value, false)
     }
 
-    /// Tries to reserve capacity for at least `additional` more key-value pairs to be inserted
-    /// in the given [`AssocList`]. The collection may reserve more space to speculatively avoid
-    /// frequent reallocations. After calling `try_reserve`,
-    /// capacity will be greater than or equal to `self.len() + additional` if it returns [`Ok(())`].
-    /// Does nothing if capacity is already sufficient.
-    /// This method preserves the contents even if an error occurs.
+    /// Insert a new element for the given `key` directly after the element
+    /// associated with `anchor`.
+    /// If the [`AssocList`] already contains an element associated with the key,
+    /// it is replaced and returned, and the element is moved directly after `anchor`.
+    /// If `key` is equal to `anchor`, the value is replaced in place.
     ///
     /// ## Errors
-    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    /// If the [`AssocList`] doesn't contain an element associated with `anchor`,
+    /// the key-value pair is returned unchanged.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
     #[inline]
-    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        self.vec.try_reserve(additional)
+    pub fn insert_after<Q>(&mut self, anchor: &Q, key: K, value: V) -> Result<Option<V>, (K, V)>
+    where
+        K: PartialEq,
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.insert_next_to(anchor, key, value, true)
     }
 
-    /// Tries to reserve the minimum capacity for at least `additional` key-value pairs
-    /// to be inserted in the given [`AssocList`]. Unlike [`try_reserve`](AssocList::try_reserve),
+    /// Implementation of [`insert_before`](AssocList::insert_before)
+    /// and [`insert_after`](AssocList::insert_after).
+    fn insert_next_to<Q>(
+        &mut self,
+        anchor: &Q,
+        key: K,
+        value: V,
+        after: bool,
+    ) -> Result<Option<V>, (K, V)>
+    where
+        K: PartialEq,
+        Q: Equivalent<K> + ?Sized,
+    {
+        let Some(anchor_index) = self.get_index_of(anchor) else {
+            return Err((key, value));
+        };
+        // anchor_index is in bounds and current_index differs from anchor_index,
+        // so neither addition nor subtraction can overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        for (current_index, (contained_key, contained_value)) in self.vec.iter_mut().enumerate() {
+            if contained_key == &key {
+                let bisher = mem::replace(contained_value, value);
+                // removing the element from its current position shifts the anchor
+                let target_index = match (current_index.cmp(&anchor_index), after) {
+                    (Ordering::Equal, _after) => current_index,
+                    (Ordering::Less, false) => anchor_index - 1,
+                    (Ordering::Less, true) | (Ordering::Greater, false) => anchor_index,
+                    (Ordering::Greater, true) => anchor_index + 1,
+                };
+                self.shift_element(current_index, target_index);
+                return Ok(Some(bisher));
+            }
+        }
+        // anchor_index is in bounds, so the addition can't overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        let index = if after { anchor_index + 1 } else { anchor_index };
+        self.vec.insert(index, (key, value));
+        Ok(None)
+    }
+
+    /// Remove the element associated with the `key` from the [`AssocList`] and return it.
+    ///
+    /// The order of the remaining elements is preserved, at the cost of shifting
+    /// all following elements.
+    /// See [`swap_remove`](AssocList::swap_remove) for a version without shifting.
+    #[must_use]
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let (_old_key, old_value) = self.remove_entry(key)?;
+        Some(old_value)
+    }
+
+    /// Remove the key-value pair associated with the `key` from the [`AssocList`] and return it.
+    ///
+    /// The order of the remaining elements is preserved, at the cost of shifting
+    /// all following elements.
+    /// See [`swap_remove_entry`](AssocList::swap_remove_entry) for a version without shifting.
+    #[must_use]
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        Some(self.vec.remove(index))
+    }
+
+    /// Remove the element associated with the `key` from the [`AssocList`] and return it,
+    /// if the `predicate` returns `true` for the stored key-value pair.
+    /// Otherwise, the [`AssocList`] is left untouched and [`None`] is returned.
+    ///
+    /// Like [`remove`](AssocList::remove), the order of the remaining elements is preserved.
+    #[must_use]
+    #[inline]
+    pub fn remove_if<Q>(&mut self, key: &Q, predicate: impl FnOnce(&K, &V) -> bool) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        let (contained_key, contained_value) = self.vec.get(index)?;
+        if !predicate(contained_key, contained_value) {
+            return None;
+        }
+        let (_old_key, old_value) = self.vec.remove(index);
+        Some(old_value)
+    }
+
+    /// Remove the first key-value pair in insertion order with a value equal to `value`
+    /// from the [`AssocList`] and return it.
+    ///
+    /// Only the first matching element is removed.
+    /// Use [`retain`](AssocList::retain) to remove all matching elements.
+    ///
+    /// Like [`remove`](AssocList::remove), the order of the remaining elements is preserved.
+    #[must_use]
+    #[inline]
+    pub fn remove_by_value<W>(&mut self, value: &W) -> Option<(K, V)>
+    where
+        V: PartialEq<W>,
+        W: ?Sized,
+    {
+        let index = self
+            .vec
+            .iter()
+            .position(|(_contained_key, contained_value)| contained_value == value)?;
+        Some(self.vec.remove(index))
+    }
+
+    /// Remove the element associated with the `key` from the [`AssocList`] and return it.
+    ///
+    /// The last element is moved to the position of the removed one,
+    /// so the order of the remaining elements is not preserved.
+    #[must_use]
+    #[inline]
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let (_old_key, old_value) = self.swap_remove_entry(key)?;
+        Some(old_value)
+    }
+
+    /// Remove the key-value pair associated with the `key` from the [`AssocList`] and return it.
+    ///
+    /// The last element is moved to the position of the removed one,
+    /// so the order of the remaining elements is not preserved.
+    #[must_use]
+    #[inline]
+    pub fn swap_remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        Some(self.vec.swap_remove(index))
+    }
+
+    /// Remove the last key-value pair from the [`AssocList`] and return it,
+    /// or [`None`] if it is empty.
+    ///
+    /// Newly inserted elements are appended at the end, so this is the most recently
+    /// inserted key-value pair.
+    /// However, [`swap_remove`](AssocList::swap_remove) moves the last element into the position
+    /// of the removed one, so afterwards this is no longer the case.
+    #[must_use]
+    #[inline]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.vec.pop()
+    }
+
+    /// Remove the last key-value pair from the [`AssocList`] and return it,
+    /// if the `predicate` returns `true` for it.
+    /// Returns [`None`] if the `predicate` returns `false` or the [`AssocList`] is empty.
+    ///
+    /// The `predicate` gets mutable access to the value, so it can be modified before removal.
+    #[inline]
+    pub fn pop_if(&mut self, predicate: impl FnOnce(&K, &mut V) -> bool) -> Option<(K, V)> {
+        let (key, value) = self.vec.last_mut()?;
+        if predicate(key, value) {
+            self.vec.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more key-value pairs to be inserted
+    /// in the given [`AssocList`].
+    /// The collection may reserve more space to speculatively avoid frequent reallocations.
+    /// After calling reserve, capacity will be greater than or equal to `self.len() + additional`.
+    /// Does nothing if capacity is already sufficient.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    /// Reserves the minimum capacity for at least `additional` more key-value pairs to be inserted
+    /// in the given [`AssocList`].
+    /// Unlike [`reserve`](AssocList::reserve), this will not deliberately over-allocate
+    /// to speculatively avoid frequent allocations. After calling `reserve_exact`,
+    /// capacity will be greater than or equal to `self.len() + additional`.
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// Note that the allocator may give the collection more space than it requests.
+    /// Therefore, capacity can not be relied upon to be precisely minimal.
+    /// Prefer [`reserve`](AssocList::reserve) if future insertions are expected.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.vec.reserve_exact(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more key-value pairs to be inserted
+    /// in the given [`AssocList`]. The collection may reserve more space to speculatively avoid
+    /// frequent reallocations. After calling `try_reserve`,
+    /// capacity will be greater than or equal to `self.len() + additional` if it returns [`Ok(())`].
+    /// Does nothing if capacity is already sufficient.
+    /// This method preserves the contents even if an error occurs.
+    ///
+    /// ## Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.vec.try_reserve(additional)
+    }
+
+    /// Tries to reserve the minimum capacity for at least `additional` key-value pairs
+    /// to be inserted in the given [`AssocList`]. Unlike [`try_reserve`](AssocList::try_reserve),
     /// this will not deliberately over-allocate to speculatively avoid frequent allocations.
     /// After calling `try_reserve_exact`, capacity will be greater than or equal to
     /// `self.len() + additional` if it returns [`Ok(())`].
     /// Does nothing if the capacity is already sufficient.
     ///
-    /// Note that the allocator may give the collection more space than it requests.
-    /// Therefore, capacity can not be relied upon to be precisely minimal.
-    /// Prefer [`try_reserve`](AssocList::try_reserve) if future insertions are expected.
+    /// Note that the allocator may give the collection more space than it requests.
+    /// Therefore, capacity can not be relied upon to be precisely minimal.
+    /// Prefer [`try_reserve`](AssocList::try_reserve) if future insertions are expected.
+    ///
+    /// ## Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.vec.try_reserve_exact(additional)
+    }
+
+    /// Shrinks the capacity of the underlying [`Vec`] with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the length and the supplied value.
+    ///
+    /// If the current capacity is less than the lower limit, this is a no-op.
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.vec.shrink_to(min_capacity);
+    }
+
+    ///Shrinks the capacity of the underlying [`Vec`] as much as possible.
+    ///
+    /// It will drop down as close as possible to the length but the allocator may still inform
+    /// the vector that there is space for a few more elements.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+
+    /// Transform all values of the [`AssocList`] with `convert`,
+    /// preserving the keys and their order.
+    ///
+    /// Since the keys are unchanged, they are not checked for uniqueness again,
+    /// in contrast to collecting a mapped iterator.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn map_values<W>(self, mut convert: impl FnMut(&K, V) -> W) -> AssocList<K, W, A>
+    where
+        A: Clone,
+    {
+        let mut vec = self.new_vec_with_capacity(self.len());
+        for (key, value) in self.vec {
+            let new_value = convert(&key, value);
+            // keys are unchanged, so they stay unique
+            vec.push((key, new_value));
+        }
+        AssocList { vec, phantom: PhantomData }
+    }
+
+    /// Transform all keys of the [`AssocList`] with `convert`, preserving their order.
+    ///
+    /// If a converted key is equal to a previously converted key, their values are combined
+    /// by `resolve`, called with the previously converted key, the earlier and the later value.
+    /// The combined value keeps the position of the earlier key-value pair.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    ///
+    /// ## Example
+    /// ```
+    /// # use associated_list::AssocList;
+    /// let counts = AssocList::from([("Apple", 1), ("banana", 2), ("APPLE", 3)]);
+    /// let normalized = counts.map_keys(str::to_ascii_lowercase, |_key, earlier, later| earlier + later);
+    /// assert_eq!(normalized.get("apple"), Some(&4));
+    /// assert_eq!(normalized.get("banana"), Some(&2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn map_keys<L: PartialEq>(
+        self,
+        mut convert: impl FnMut(K) -> L,
+        mut resolve: impl FnMut(&L, V, V) -> V,
+    ) -> AssocList<L, V, A>
+    where
+        A: Clone,
+    {
+        let vec = self.new_vec_with_capacity(self.len());
+        let mut mapped = AssocList { vec, phantom: PhantomData };
+        for (key, value) in self.vec {
+            mapped.insert_or_resolve(convert(key), value, &mut resolve);
+        }
+        mapped
+    }
+
+    /// Transform all values of the [`AssocList`] with the fallible function `convert`,
+    /// preserving the keys and their order.
+    ///
+    /// ## Errors
+    /// Returns the first error returned by `convert`.
+    /// All values converted up to this point, as well as the remaining elements, are dropped.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn try_map_values<W, E>(
+        self,
+        mut convert: impl FnMut(&K, V) -> Result<W, E>,
+    ) -> Result<AssocList<K, W, A>, E>
+    where
+        A: Clone,
+    {
+        let mut vec = self.new_vec_with_capacity(self.len());
+        for (key, value) in self.vec {
+            let new_value = convert(&key, value)?;
+            // keys are unchanged, so they stay unique
+            vec.push((key, new_value));
+        }
+        Ok(AssocList { vec, phantom: PhantomData })
+    }
+
+    /// Retain only the key-value pairs for which the predicate `keep` returns `true`,
+    /// preserving the order of the remaining elements.
+    ///
+    /// The predicate gets mutable access to the values, so they can be modified
+    /// regardless of whether they are retained.
+    #[inline]
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &mut V) -> bool) {
+        self.vec.retain_mut(|(key, value)| keep(key, value));
+    }
+
+    /// Retain only the key-value pairs for which `convert` returns [`Some`],
+    /// replacing their values with the returned ones.
+    ///
+    /// `convert` takes ownership of each value, in the order of the [`AssocList`].
+    /// The order of the remaining elements and the capacity of the [`AssocList`] are preserved.
+    /// To take ownership of the values, the key-value pairs are moved through a temporary buffer,
+    /// allocated with the allocator of the [`AssocList`].
+    /// If `convert` panics, only the key-value pairs retained so far are kept.
+    /// To change the value type, see [`filter_map_values`](AssocList::filter_map_values).
+    ///
+    /// ## Panics
+    /// Panics if the allocation of the temporary buffer fails.
+    #[inline]
+    pub fn retain_map(&mut self, mut convert: impl FnMut(&K, V) -> Option<V>)
+    where
+        A: Clone,
+    {
+        let mut pairs = self.new_vec_with_capacity(self.len());
+        pairs.append(&mut self.vec);
+        for (key, value) in pairs {
+            if let Some(new_value) = convert(&key, value) {
+                // at most as many elements as before, so the capacity suffices
+                self.vec.push((key, new_value));
+            }
+        }
+    }
+
+    /// Create a new [`AssocList`] with the values transformed by `convert`,
+    /// which may change the value type.
+    /// Key-value pairs for which `convert` returns [`None`] are dropped.
+    ///
+    /// Since the keys are unchanged, they are not checked for uniqueness again,
+    /// in contrast to collecting a filtered iterator.
+    /// To keep the value type and the capacity, see [`retain_map`](AssocList::retain_map).
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn filter_map_values<W>(
+        self,
+        mut convert: impl FnMut(&K, V) -> Option<W>,
+    ) -> AssocList<K, W, A>
+    where
+        A: Clone,
+    {
+        let mut vec = self.new_vec_with_capacity(self.len());
+        for (key, value) in self.vec {
+            if let Some(new_value) = convert(&key, value) {
+                // keys are unchanged, so they stay unique
+                vec.push((key, new_value));
+            }
+        }
+        AssocList { vec, phantom: PhantomData }
+    }
+
+    /// Retain only the key-value pairs for which the fallible predicate `keep` returns `Ok(true)`,
+    /// preserving the order of the remaining elements.
+    ///
+    /// ## Errors
+    /// Returns the first error returned by `keep`.
+    /// All elements visited before the error have been processed,
+    /// while the element causing the error and all following elements remain untouched.
+    #[inline]
+    pub fn try_retain<E>(
+        &mut self,
+        mut keep: impl FnMut(&K, &mut V) -> Result<bool, E>,
+    ) -> Result<(), E> {
+        let mut result = Ok(());
+        self.vec.retain_mut(|(key, value)| {
+            if result.is_err() {
+                // skip all elements after an error
+                return true;
+            }
+            match keep(key, value) {
+                Ok(keep_element) => keep_element,
+                Err(error) => {
+                    result = Err(error);
+                    true
+                },
+            }
+        });
+        result
+    }
+
+    /// Move the key-value pair associated with the `key` from the [`AssocList`] to `destination`,
+    /// preserving the order of the remaining elements.
+    /// If `destination` already contains an element associated with the key, its value is replaced.
+    ///
+    /// Returns `true`, if an element was moved.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity of `destination` exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn move_entry_to<Q, A2: Allocator>(
+        &mut self,
+        key: &Q,
+        destination: &mut AssocList<K, V, A2>,
+    ) -> bool
+    where
+        K: PartialEq,
+        Q: Equivalent<K> + ?Sized,
+    {
+        let Some(index) = self
+            .vec
+            .iter()
+            .position(|(contained_key, _contained_value)| key.equivalent(contained_key))
+        else {
+            return false;
+        };
+        let (moved_key, moved_value) = self.vec.remove(index);
+        let _ = destination.insert(moved_key, moved_value);
+        true
+    }
+
+    /// Move all key-value pairs from `other` to the end of the [`AssocList`],
+    /// preserving their order and leaving `other` empty.
+    /// If the [`AssocList`] already contains an element associated with a key of `other`,
+    /// its value is replaced in place, so the value of `other` takes precedence.
+    ///
+    /// Both [`AssocList`]s have to use the same allocator type.
+    /// Use [`extend`](Extend::extend) with [`drain`](AssocList::drain) otherwise.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn append(&mut self, other: &mut Self)
+    where
+        K: PartialEq,
+    {
+        self.vec.reserve(other.len());
+        for (key, value) in other.vec.drain(..) {
+            let _ = self.insert(key, value);
+        }
+    }
+
+    /// Insert all key-value pairs of `iter`, like [`extend`](Extend::extend).
+    /// If the [`AssocList`] already contains an element associated with a key,
+    /// including keys inserted earlier from `iter`, `on_conflict` is called with the key,
+    /// the contained value and the new value.
+    ///
+    /// In contrast to [`extend`](Extend::extend), which replaces the contained value,
+    /// `on_conflict` decides how the new value is combined with the contained one,
+    /// e.g. by folding it into the contained value.
+    ///
+    /// New keys are appended in the order of their first occurrence,
+    /// contained keys keep their position.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    ///
+    /// ## Example
+    /// ```
+    /// # use associated_list::AssocList;
+    /// let mut config = AssocList::from([("plugins", vec!["core"]), ("theme", vec!["dark"])]);
+    /// let user = AssocList::from([("plugins", vec!["git"]), ("font", vec!["mono"])]);
+    /// config.extend_with(user, |_key, plugins, user_plugins| plugins.extend(user_plugins));
+    /// assert_eq!(config.get("plugins"), Some(&vec!["core", "git"]));
+    /// assert_eq!(config.get("font"), Some(&vec!["mono"]));
+    /// ```
+    #[doc(alias = "merge_with")]
+    #[inline]
+    pub fn extend_with<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+        mut on_conflict: impl FnMut(&K, &mut V, V),
+    ) where
+        K: PartialEq,
+    {
+        let iter = iter.into_iter();
+        let (lower_bound, _upper_bound) = iter.size_hint();
+        self.vec.reserve(lower_bound);
+        for (key, value) in iter {
+            if let Some((_index, contained_key, contained_value)) = self.get_full_mut(&key) {
+                on_conflict(contained_key, contained_value, value);
+            } else {
+                self.vec.push((key, value));
+            }
+        }
+    }
+
+    /// Insert all key-value pairs of `iter`, requiring their keys to be unique.
+    ///
+    /// ## Errors
+    /// Stops at the first key already contained in the [`AssocList`], either from before or
+    /// inserted from `iter`, and returns a [`DuplicateKeyError`] containing the rejected key.
+    /// All key-value pairs up to this point stay inserted, their number is available as
+    /// [`index`](DuplicateKeyError::index). The value of the rejected key-value pair is dropped,
+    /// the remaining key-value pairs of `iter` are not consumed.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn extend_unique<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), DuplicateKeyError<K>>
+    where
+        K: PartialEq,
+    {
+        let iter = iter.into_iter();
+        let (lower_bound, _upper_bound) = iter.size_hint();
+        self.vec.reserve(lower_bound);
+        for (index, (key, value)) in iter.enumerate() {
+            if let Some(first_index) = self.get_index_of(&key) {
+                return Err(DuplicateKeyError { key, index, first_index });
+            }
+            self.vec.push((key, value));
+        }
+        Ok(())
+    }
+
+    /// Move all key-value pairs from `other` to the front of the [`AssocList`],
+    /// preserving their order and leaving `other` empty.
+    /// If the [`AssocList`] already contains an element associated with a key of `other`,
+    /// it is removed from its old position, so the value of `other` takes precedence.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn prepend(&mut self, other: &mut Self)
+    where
+        K: PartialEq,
+    {
+        self.vec.retain(|(key, _value)| {
+            !other.vec.iter().any(|(other_key, _other_value)| other_key == key)
+        });
+        // dropping the Splice-iterator inserts the elements
+        let _ = self.vec.splice(0..0, other.vec.drain(..));
+    }
+
+    /// Update the value associated with the `key`, or insert a new one if there is none.
+    ///
+    /// Exactly one of the closures is called: `update` if the [`AssocList`] already contains
+    /// an element associated with the `key`, otherwise `insert`.
+    /// Returns a mutable reference to the resulting value.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn upsert(
+        &mut self,
+        key: K,
+        insert: impl FnOnce() -> V,
+        update: impl FnOnce(&mut V),
+    ) -> &mut V
+    where
+        K: PartialEq,
+    {
+        if let Some(index) =
+            self.vec.iter().position(|(contained_key, _contained_value)| contained_key == &key)
+        {
+            let (_key, value) = self.vec.get_mut(index).expect("Element has just been found!");
+            update(value);
+            value
+        } else {
+            self.vec.push((key, insert()));
+            let (_key, value) = self.vec.last_mut().expect("Element has just been added!");
+            value
+        }
+    }
+
+    /// Regroup the key-value pairs of the [`AssocList`] by the group returned by `group_of`.
+    ///
+    /// The groups are ordered by their first occurrence, the key-value pairs in each group
+    /// keep their relative order.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn group_values_by<G: PartialEq>(
+        self,
+        mut group_of: impl FnMut(&K, &V) -> G,
+    ) -> AssocList<G, Vec<(K, V)>> {
+        let mut groups = AssocList::new();
+        for (key, value) in self.vec {
+            let group = group_of(&key, &value);
+            groups.entry(group).or_insert(Vec::new()).push((key, value));
+        }
+        groups
+    }
+
+    /// Remove all key-value pairs, whose value is projected by `project` to something
+    /// equal to the projection of an earlier value.
+    /// The order of the remaining elements is preserved.
+    ///
+    /// Returns the number of removed key-value pairs.
+    ///
+    /// Since the projections are only required to implement [`PartialEq`],
+    /// this method has a runtime characteristic of `O(n^2)`.
+    ///
+    /// ## Panics
+    /// Panics if the capacity required to remember the projections exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn dedup_by_value<T: PartialEq>(&mut self, mut project: impl FnMut(&V) -> T) -> usize {
+        let len_before = self.len();
+        let mut seen_projections = Vec::new();
+        self.vec.retain(|(_key, value)| {
+            let projection = project(value);
+            if seen_projections.contains(&projection) {
+                false
+            } else {
+                seen_projections.push(projection);
+                true
+            }
+        });
+        // retain can only remove elements
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            len_before - self.len()
+        }
+    }
+
+    /// Swap keys and values of the [`AssocList`], grouping all keys associated with equal values.
+    ///
+    /// The values are ordered by their first occurrence, the keys for each value
+    /// keep their relative order.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn invert_grouped(self) -> AssocList<V, Vec<K>>
+    where
+        V: PartialEq,
+    {
+        let mut inverted = AssocList::new();
+        for (key, value) in self.vec {
+            inverted.entry(value).or_insert(Vec::new()).push(key);
+        }
+        inverted
+    }
+
+    /// Swap keys and values of the [`AssocList`].
+    ///
+    /// For equal values, the later key is kept, mirroring [`insert`](AssocList::insert).
+    /// The position is determined by the first occurrence of the value.
+    /// Use [`invert_with`](AssocList::invert_with) to choose a different key,
+    /// or [`invert_grouped`](AssocList::invert_grouped) to keep all keys.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn invert(self) -> AssocList<V, K, A>
+    where
+        V: PartialEq,
+        A: Clone,
+    {
+        self.invert_with(|_value, _earlier, later| later)
+    }
+
+    /// Swap keys and values of the [`AssocList`].
+    ///
+    /// For equal values, the keys are combined by `resolve`, called with the earlier value,
+    /// the earlier and the later key.
+    /// The position is determined by the first occurrence of the value.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn invert_with(self, mut resolve: impl FnMut(&V, K, K) -> K) -> AssocList<V, K, A>
+    where
+        V: PartialEq,
+        A: Clone,
+    {
+        let vec = self.new_vec_with_capacity(self.len());
+        let mut inverted = AssocList { vec, phantom: PhantomData };
+        for (key, value) in self.vec {
+            inverted.insert_or_resolve(value, key, &mut resolve);
+        }
+        inverted
+    }
+
+    /// Iterate over all keys associated with a value equal to `value`, in insertion order.
+    #[inline]
+    pub fn keys_for_value<'a, W>(&'a self, value: &'a W) -> impl Iterator<Item = &'a K> + 'a
+    where
+        V: PartialEq<W>,
+        W: ?Sized,
+    {
+        self.vec
+            .iter()
+            .filter(move |(_key, contained_value)| contained_value == value)
+            .map(|(key, _value)| key)
+    }
+
+    /// Get the first key associated with a value equal to `value`.
+    #[must_use]
+    #[inline]
+    pub fn find_key_for_value<W>(&self, value: &W) -> Option<&K>
+    where
+        V: PartialEq<W>,
+        W: ?Sized,
+    {
+        let (key, _value) =
+            self.vec.iter().find(|(_key, contained_value)| contained_value == value)?;
+        Some(key)
+    }
+
+    /// Get the key-value pair with the smallest value.
+    ///
+    /// Values that are not comparable to themselves (e.g. [`f32::NAN`]) are skipped.
+    /// If a value is not comparable to the current minimum, the current minimum is kept.
+    /// If several values are equally minimal, the first one is returned.
+    #[must_use]
+    #[inline]
+    pub fn min_by_value(&self) -> Option<(&K, &V)>
+    where
+        V: PartialOrd,
+    {
+        self.extremum_by(|_key, value| value, PartialOrd::partial_cmp, Ordering::Less)
+    }
+
+    /// Get the key-value pair with the largest value.
+    ///
+    /// Values that are not comparable to themselves (e.g. [`f32::NAN`]) are skipped.
+    /// If a value is not comparable to the current maximum, the current maximum is kept.
+    /// If several values are equally maximal, the first one is returned.
+    #[must_use]
+    #[inline]
+    pub fn max_by_value(&self) -> Option<(&K, &V)>
+    where
+        V: PartialOrd,
+    {
+        self.extremum_by(|_key, value| value, PartialOrd::partial_cmp, Ordering::Greater)
+    }
+
+    /// Get the key-value pair with the smallest value with respect to the `compare`-function.
+    ///
+    /// If several values are equally minimal, the first one is returned.
+    #[must_use]
+    #[inline]
+    pub fn min_by_value_by(&self, mut compare: impl FnMut(&V, &V) -> Ordering) -> Option<(&K, &V)> {
+        self.extremum_by(
+            |_key, value| value,
+            |value, other| Some(compare(value, other)),
+            Ordering::Less,
+        )
+    }
+
+    /// Get the key-value pair with the largest value with respect to the `compare`-function.
+    ///
+    /// If several values are equally maximal, the first one is returned.
+    #[must_use]
+    #[inline]
+    pub fn max_by_value_by(&self, mut compare: impl FnMut(&V, &V) -> Ordering) -> Option<(&K, &V)> {
+        self.extremum_by(
+            |_key, value| value,
+            |value, other| Some(compare(value, other)),
+            Ordering::Greater,
+        )
+    }
+
+    /// Get the key-value pair with the smallest key.
+    ///
+    /// Keys that are not comparable to themselves (e.g. [`f32::NAN`]) are never returned.
+    /// If a key is not comparable to the current minimum, the current minimum is kept.
+    #[must_use]
+    #[inline]
+    pub fn min_key_value(&self) -> Option<(&K, &V)>
+    where
+        K: PartialOrd,
+    {
+        self.extremum_by(|key, _value| key, PartialOrd::partial_cmp, Ordering::Less)
+    }
+
+    /// Get the key-value pair with the largest key.
+    ///
+    /// Keys that are not comparable to themselves (e.g. [`f32::NAN`]) are never returned.
+    /// If a key is not comparable to the current maximum, the current maximum is kept.
+    #[must_use]
+    #[inline]
+    pub fn max_key_value(&self) -> Option<(&K, &V)>
+    where
+        K: PartialOrd,
+    {
+        self.extremum_by(|key, _value| key, PartialOrd::partial_cmp, Ordering::Greater)
+    }
+
+    /// Get the key-value pair with the smallest projection by `project`.
+    ///
+    /// Projections that are not comparable to themselves are skipped.
+    /// If a projection is not comparable to the current minimum, the current minimum is kept.
+    /// If several projections are equally minimal, the first one is returned.
+    #[must_use]
+    #[inline]
+    pub fn min_by_key<T: PartialOrd>(&self, project: impl FnMut(&K, &V) -> T) -> Option<(&K, &V)> {
+        self.extremum_by(project, PartialOrd::partial_cmp, Ordering::Less)
+    }
+
+    /// Get the key-value pair with the largest projection by `project`.
+    ///
+    /// Projections that are not comparable to themselves are skipped.
+    /// If a projection is not comparable to the current maximum, the current maximum is kept.
+    /// If several projections are equally maximal, the first one is returned.
+    #[must_use]
+    #[inline]
+    pub fn max_by_key<T: PartialOrd>(&self, project: impl FnMut(&K, &V) -> T) -> Option<(&K, &V)> {
+        self.extremum_by(project, PartialOrd::partial_cmp, Ordering::Greater)
+    }
+
+    /// Get the first key-value pair, whose projection is not surpassed in the direction of `wanted`.
+    ///
+    /// Projections that are not comparable to themselves are skipped.
+    fn extremum_by<'s, T>(
+        &'s self,
+        mut project: impl FnMut(&'s K, &'s V) -> T,
+        mut compare: impl FnMut(&T, &T) -> Option<Ordering>,
+        wanted: Ordering,
+    ) -> Option<(&'s K, &'s V)> {
+        let mut extremum: Option<(T, (&K, &V))> = None;
+        for (key, value) in &self.vec {
+            let projection = project(key, value);
+            let replace = match &extremum {
+                None => compare(&projection, &projection).is_some(),
+                Some((extremum_projection, _extremum)) => {
+                    compare(&projection, extremum_projection) == Some(wanted)
+                },
+            };
+            if replace {
+                extremum = Some((projection, (key, value)));
+            }
+        }
+        extremum.map(|(_projection, key_value)| key_value)
+    }
+
+    /// Keep only the first `len` key-value pairs in insertion order, removing all others.
+    ///
+    /// If `len` is greater or equal to the [`AssocList`]'s current length, this has no effect.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len);
+    }
+
+    /// Keep only the last `n` key-value pairs in insertion order, removing all others.
+    ///
+    /// If `n` is greater or equal to the [`AssocList`]'s current length, this has no effect.
+    #[inline]
+    pub fn keep_last(&mut self, n: usize) {
+        if let Some(excess) = self.len().checked_sub(n) {
+            // dropping the Drain-iterator removes the elements
+            let _ = self.vec.drain(..excess);
+        }
+    }
+
+    /// Replace the value associated with the `key` with `new`,
+    /// if the current value is equal to `expected`.
+    /// On success, the previous value is returned.
+    ///
+    /// ## Errors
+    /// If the [`AssocList`] doesn't contain a value associated with the `key`,
+    /// or the current value differs from `expected`, the rejected `new` value is returned
+    /// as part of a [`CasError`].
+    #[inline]
+    pub fn compare_and_swap<Q>(&mut self, key: &Q, expected: &V, new: V) -> Result<V, CasError<V>>
+    where
+        Q: Equivalent<K> + ?Sized,
+        V: PartialEq,
+    {
+        match self.get_mut(key) {
+            None => Err(CasError::MissingKey(new)),
+            Some(value) if value == expected => Ok(mem::replace(value, new)),
+            Some(_value) => Err(CasError::Mismatch(new)),
+        }
+    }
+
+    /// Move the key-value pair at position `from` to position `to`,
+    /// shifting all elements in between.
+    ///
+    /// This has no effect if `from` and `to` are equal.
+    ///
+    /// ## Panics
+    /// Panics if `from` or `to` is out of bounds.
+    #[inline]
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        let len = self.len();
+        assert!(from < len, "move_index from (is {from}) should be < len (is {len})");
+        assert!(to < len, "move_index to (is {to}) should be < len (is {len})");
+        self.shift_element(from, to);
+    }
+
+    /// Swap the key-value pairs at positions `first` and `second`,
+    /// leaving all other elements in place.
+    ///
+    /// This has no effect if `first` and `second` are equal.
+    ///
+    /// ## Panics
+    /// Panics if `first` or `second` is out of bounds.
+    #[inline]
+    pub fn swap_indices(&mut self, first: usize, second: usize) {
+        self.vec.swap(first, second);
+    }
+
+    /// Reverse the order of the key-value pairs in the [`AssocList`], in place.
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.vec.reverse();
+    }
+
+    /// Rotate the key-value pairs in-place such that the first `mid` elements move to the end,
+    /// while the others move to the front.
+    ///
+    /// ## Panics
+    /// Panics if `mid` is greater than the [`AssocList`]'s length.
+    #[inline]
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.vec.rotate_left(mid);
+    }
+
+    /// Rotate the key-value pairs in-place such that the last `count` elements move to the front,
+    /// while the others move to the end.
+    ///
+    /// ## Panics
+    /// Panics if `count` is greater than the [`AssocList`]'s length.
+    #[inline]
+    pub fn rotate_right(&mut self, count: usize) {
+        self.vec.rotate_right(count);
+    }
+
+    /// Split the [`AssocList`] at position `at`.
+    /// Afterwards, the [`AssocList`] contains the elements `[0, at)`,
+    /// while the returned [`AssocList`] contains the elements `[at, len)`.
+    /// Both keep the relative order of their elements.
+    ///
+    /// The returned [`AssocList`] uses a clone of the allocator.
+    ///
+    /// ## Panics
+    /// Panics if `at` is greater than the [`AssocList`]'s length.
+    ///
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        A: Clone,
+    {
+        AssocList { vec: self.vec.split_off(at), phantom: self.phantom }
+    }
+
+    /// Split the [`AssocList`] by comparing all keys with `key`.
+    /// Afterwards, the [`AssocList`] contains all elements with a key less than `key`,
+    /// or not comparable to it,
+    /// while the returned [`AssocList`] contains all elements with a key greater or equal to `key`.
+    /// Both keep the relative order of their elements.
+    ///
+    /// The [`AssocList`] is not required to be sorted, all elements are compared with `key`.
+    ///
+    /// The returned [`AssocList`] uses a clone of the allocator.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn split_off_by_key<Q>(&mut self, key: &Q) -> Self
+    where
+        K: Borrow<Q>,
+        Q: PartialOrd + ?Sized,
+        A: Clone,
+    {
+        let lower = self.new_vec_with_capacity(self.len());
+        let mut upper = self.new_vec_with_capacity(0);
+        for (contained_key, contained_value) in mem::replace(&mut self.vec, lower) {
+            if contained_key.borrow() >= key {
+                upper.push((contained_key, contained_value));
+            } else {
+                self.vec.push((contained_key, contained_value));
+            }
+        }
+        AssocList { vec: upper, phantom: self.phantom }
+    }
+
+    /// Split the [`AssocList`] into the key-value pairs, for which the `predicate` returns `true`,
+    /// and those for which it returns `false`.
+    /// Both keep the relative order of their elements.
+    ///
+    /// The second [`AssocList`] uses a clone of the allocator.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn partition(self, mut predicate: impl FnMut(&K, &V) -> bool) -> (Self, Self)
+    where
+        A: Clone,
+    {
+        let mut matching = self.new_vec_with_capacity(self.len());
+        let mut rest = self.new_vec_with_capacity(self.len());
+        for (key, value) in self.vec {
+            if predicate(&key, &value) {
+                matching.push((key, value));
+            } else {
+                rest.push((key, value));
+            }
+        }
+        let matching_list = AssocList { vec: matching, phantom: PhantomData };
+        let rest_list = AssocList { vec: rest, phantom: PhantomData };
+        (matching_list, rest_list)
+    }
+
+    /// Remove all elements associated with one of the `keys` from the [`AssocList`],
+    /// preserving the order of the remaining elements.
+    ///
+    /// Returns the number of removed key-value pairs.
+    ///
+    /// ## Panics
+    /// Panics if the capacity required to remember the `keys` exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn remove_many<'a, Q, I>(&mut self, keys: I) -> usize
+    where
+        Q: Equivalent<K> + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        let keys: Vec<&Q> = keys.into_iter().collect();
+        let len_before = self.len();
+        self.vec.retain(|(key, _value)| !keys.iter().any(|query| query.equivalent(key)));
+        // retain can only remove elements
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            len_before - self.len()
+        }
+    }
+
+    /// Remove all elements with a key starting with `prefix` from the [`AssocList`],
+    /// preserving the order of the remaining elements.
+    ///
+    /// Returns the number of removed key-value pairs.
+    #[inline]
+    pub fn remove_prefix(&mut self, prefix: &str) -> usize
+    where
+        K: Borrow<str>,
+    {
+        let len_before = self.len();
+        self.vec.retain(|(key, _value)| !key.borrow().starts_with(prefix));
+        // retain can only remove elements
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            len_before - self.len()
+        }
+    }
+
+    /// Remove all elements with a key starting with the slice `prefix` from the [`AssocList`],
+    /// preserving the order of the remaining elements.
+    ///
+    /// Returns the number of removed key-value pairs.
+    #[inline]
+    pub fn remove_slice_prefix<T: PartialEq>(&mut self, prefix: &[T]) -> usize
+    where
+        K: Borrow<[T]>,
+    {
+        let len_before = self.len();
+        self.vec.retain(|(key, _value)| !key.borrow().starts_with(prefix));
+        // retain can only remove elements
+        #[allow(clippy::arithmetic_side_effects)]
+        {
+            len_before - self.len()
+        }
+    }
+
+    /// Replace the key `old` with `new`, keeping the value and the position of the element.
+    /// On success, the previous key is returned.
+    ///
+    /// If `new` is equal to `old`, the stored key is replaced.
+    ///
+    /// ## Errors
+    /// If the [`AssocList`] doesn't contain an element associated with `old`,
+    /// or it already contains another element associated with `new`,
+    /// the [`AssocList`] is left untouched and `new` is returned.
+    #[inline]
+    pub fn replace_key<Q>(&mut self, old: &Q, new: K) -> Result<K, K>
+    where
+        K: PartialEq,
+        Q: Equivalent<K> + ?Sized,
+    {
+        let new_index =
+            self.vec.iter().position(|(contained_key, _contained_value)| contained_key == &new);
+        for (index, (contained_key, _contained_value)) in self.vec.iter_mut().enumerate() {
+            if old.equivalent(contained_key) {
+                if new_index.is_some_and(|new_index| new_index != index) {
+                    // keys must stay unique
+                    return Err(new);
+                }
+                return Ok(mem::replace(contained_key, new));
+            }
+        }
+        Err(new)
+    }
+
+    /// Apply `update` to the value associated with the `key`.
+    ///
+    /// Returns `true`, if the [`AssocList`] contains an element associated with the `key`.
+    #[inline]
+    pub fn update<Q>(&mut self, key: &Q, update: impl FnOnce(&mut V)) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        if let Some(value) = self.get_mut(key) {
+            update(value);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Apply `update` to the value associated with the `key`,
+    /// or insert `default` if there is none.
+    ///
+    /// Returns a mutable reference to the resulting value.
+    /// See [`upsert`](AssocList::upsert) for a version creating the new value lazily.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn update_or_insert(&mut self, key: K, default: V, update: impl FnOnce(&mut V)) -> &mut V
+    where
+        K: PartialEq,
+    {
+        self.upsert(key, || default, update)
+    }
+
+    /// Compute a new value for the `key` from its current value, if there is one.
+    ///
+    /// The current value is moved out of the [`AssocList`] and passed to `compute`.
+    /// If `compute` returns a new value, it is associated with the `key`,
+    /// either at the position of the previous value or appended at the end.
+    /// Otherwise, the element associated with the `key` is removed,
+    /// preserving the order of the remaining elements.
+    ///
+    /// If the [`AssocList`] already contains an element associated with the `key`,
+    /// the stored key is kept and the passed `key` is dropped.
+    ///
+    /// Returns a mutable reference to the new value.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    ///
+    /// If `compute` panics, the element associated with the `key` is lost,
+    /// and the last element is moved to its position, so the order is not preserved.
+    #[inline]
+    pub fn compute(
+        &mut self,
+        key: K,
+        compute: impl FnOnce(&K, Option<V>) -> Option<V>,
+    ) -> Option<&mut V>
+    where
+        K: PartialEq,
+    {
+        let Some(index) =
+            self.vec.iter().position(|(contained_key, _contained_value)| contained_key == &key)
+        else {
+            let new_value = compute(&key, None)?;
+            self.vec.push((key, new_value));
+            let (_key, value) = self.vec.last_mut()?;
+            return Some(value);
+        };
+        // temporarily move the contained element to the end, to take ownership of its value
+        let (contained_key, contained_value) = self.vec.swap_remove(index);
+        let Some(new_value) = compute(&contained_key, Some(contained_value)) else {
+            // restore the order of the remaining elements, shifting them only once
+            if index < self.len() {
+                let moved_element = self.vec.remove(index);
+                self.vec.push(moved_element);
+            }
+            return None;
+        };
+        self.vec.push((contained_key, new_value));
+        let last_index = self.len().saturating_sub(1);
+        self.vec.swap(index, last_index);
+        let (_key, value) = self.vec.get_mut(index)?;
+        Some(value)
+    }
+
+    /// Swap the values associated with the keys `first` and `second`,
+    /// leaving the keys and the order of the elements unchanged.
+    ///
+    /// Returns `true`, if the values were swapped,
+    /// i.e. both keys are contained in the [`AssocList`] and associated with different elements.
+    #[inline]
+    pub fn swap_values<Q>(&mut self, first: &Q, second: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let (Some(first_index), Some(second_index)) =
+            (self.get_index_of(first), self.get_index_of(second))
+        else {
+            return false;
+        };
+        let (lower_index, upper_index) = match first_index.cmp(&second_index) {
+            Ordering::Less => (first_index, second_index),
+            Ordering::Equal => return false,
+            Ordering::Greater => (second_index, first_index),
+        };
+        let (head, tail) = self.vec.split_at_mut(upper_index);
+        let (Some((_lower_key, lower_value)), Some((_upper_key, upper_value))) =
+            (head.get_mut(lower_index), tail.first_mut())
+        else {
+            return false;
+        };
+        mem::swap(lower_value, upper_value);
+        true
+    }
+
+    /// Move the key-value pair associated with the `key` to the front of the [`AssocList`],
+    /// preserving the relative order of the other elements.
+    ///
+    /// Returns `true`, if the [`AssocList`] contains an element associated with the `key`.
+    #[inline]
+    pub fn move_to_front<Q>(&mut self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let Some(index) = self.get_index_of(key) else {
+            return false;
+        };
+        self.shift_element(index, 0);
+        true
+    }
+
+    /// Move the key-value pair associated with the `key` to the back of the [`AssocList`],
+    /// preserving the relative order of the other elements.
+    ///
+    /// Returns `true`, if the [`AssocList`] contains an element associated with the `key`.
+    #[inline]
+    pub fn move_to_back<Q>(&mut self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let Some(index) = self.get_index_of(key) else {
+            return false;
+        };
+        // the AssocList contains at least the found element, so the subtraction can't overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        let last_index = self.len() - 1;
+        self.shift_element(index, last_index);
+        true
+    }
+
+    /// Replace the content of the [`AssocList`] with the key-value pairs of `iter`,
+    /// reusing the existing allocation.
+    /// For duplicate keys, the later value is kept.
+    ///
+    /// The capacity is never reduced.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn assign<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I)
+    where
+        K: PartialEq,
+    {
+        self.vec.clear();
+        self.extend(iter);
+    }
+
+    /// Remove all elements associated with one of the `keys` from the [`AssocList`],
+    /// returning them as a new [`AssocList`].
+    ///
+    /// Both [`AssocList`]s keep the relative order of their elements,
+    /// i.e. the order of `keys` is irrelevant.
+    ///
+    /// The returned [`AssocList`] uses a clone of the allocator.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn extract_keys<'a, Q, I>(&mut self, keys: I) -> Self
+    where
+        Q: Equivalent<K> + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+        A: Clone,
+    {
+        let keys: Vec<&Q> = keys.into_iter().collect();
+        let remaining = self.new_vec_with_capacity(self.len());
+        let mut extracted = self.new_vec_with_capacity(keys.len());
+        for (key, value) in mem::replace(&mut self.vec, remaining) {
+            if keys.iter().any(|query| query.equivalent(&key)) {
+                extracted.push((key, value));
+            } else {
+                self.vec.push((key, value));
+            }
+        }
+        AssocList { vec: extracted, phantom: self.phantom }
+    }
+
+    /// Retain only the key-value pairs associated with one of the `keys`,
+    /// preserving the order of the remaining elements.
+    ///
+    /// See [`remove_many`](AssocList::remove_many) for the opposite operation.
+    ///
+    /// ## Panics
+    /// Panics if the capacity required to remember the `keys` exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn retain_keys<'a, Q, I>(&mut self, keys: I)
+    where
+        Q: Equivalent<K> + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        let keys: Vec<&Q> = keys.into_iter().collect();
+        self.vec.retain(|(key, _value)| keys.iter().any(|query| query.equivalent(key)));
+    }
+
+    /// Retain only the key-value pairs with a key contained in `other`,
+    /// preserving the order of the remaining elements.
+    /// The values of `other` are ignored.
+    #[inline]
+    pub fn retain_keys_in<V2, A2: Allocator>(&mut self, other: &AssocList<K, V2, A2>)
+    where
+        K: PartialEq,
+    {
+        self.vec.retain(|(key, _value)| other.contains_key(key));
+    }
+
+    /// Remove all key-value pairs with a key contained in `other`,
+    /// preserving the order of the remaining elements.
+    /// The values of `other` are ignored.
+    #[inline]
+    pub fn remove_keys_in<V2, A2: Allocator>(&mut self, other: &AssocList<K, V2, A2>)
+    where
+        K: PartialEq,
+    {
+        self.vec.retain(|(key, _value)| !other.contains_key(key));
+    }
+
+    /// Iterate over the key-value pairs of the [`AssocList`], followed by the key-value pairs
+    /// of `other` with a key not contained in the [`AssocList`].
+    ///
+    /// For keys contained in both, the value of the [`AssocList`] is used.
+    /// The iterator is evaluated lazily, but checking the keys of `other`
+    /// has a runtime characteristic of `O(n*m)`.
+    #[inline]
+    pub fn union<'a, A2: Allocator>(
+        &'a self,
+        other: &'a AssocList<K, V, A2>,
+    ) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: PartialEq,
+    {
+        let other_only = other.vec.iter().filter(|(key, _value)| !self.contains_key(key));
+        self.vec.iter().chain(other_only).map(|(key, value)| (key, value))
+    }
+
+    /// Iterate over the key-value pairs of the [`AssocList`] with a key contained in `other`,
+    /// together with the value associated in `other`.
+    ///
+    /// The iterator is evaluated lazily in the order of the [`AssocList`],
+    /// but looking up the keys in `other` has a runtime characteristic of `O(n*m)`.
+    #[inline]
+    pub fn intersection<'a, V2, A2: Allocator>(
+        &'a self,
+        other: &'a AssocList<K, V2, A2>,
+    ) -> impl Iterator<Item = (&'a K, &'a V, &'a V2)>
+    where
+        K: PartialEq,
+    {
+        self.vec
+            .iter()
+            .filter_map(|(key, value)| other.get(key).map(|other_value| (key, value, other_value)))
+    }
+
+    /// Iterate over the key-value pairs of the [`AssocList`] with a key not contained in `other`,
+    /// in insertion order.
+    ///
+    /// The values of `other` are ignored.
+    /// The iterator is evaluated lazily, but checking the keys in `other`
+    /// has a runtime characteristic of `O(n*m)`.
+    #[inline]
+    pub fn difference<'a, V2, A2: Allocator>(
+        &'a self,
+        other: &'a AssocList<K, V2, A2>,
+    ) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: PartialEq,
+    {
+        self.vec
+            .iter()
+            .filter(|(key, _value)| !other.contains_key(key))
+            .map(|(key, value)| (key, value))
+    }
+
+    /// Iterate over the key-value pairs of the [`AssocList`] with a key not contained in `other`,
+    /// followed by the key-value pairs of `other` with a key not contained in the [`AssocList`].
+    ///
+    /// Both parts keep their insertion order.
+    /// The iterator is evaluated lazily, but checking the keys
+    /// has a runtime characteristic of `O(n*m)`.
+    #[inline]
+    pub fn symmetric_difference<'a, A2: Allocator>(
+        &'a self,
+        other: &'a AssocList<K, V, A2>,
+    ) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: PartialEq,
+    {
+        self.difference(other).chain(other.difference(self))
+    }
+
+    /// Does `other` contain every key of the [`AssocList`], associated with an equal value.
+    ///
+    /// Values are compared with [`PartialEq`], so a value not equal to itself
+    /// (e.g. [`f32::NAN`]) is never contained in `other`.
+    /// Returns at the first mismatch, but has a runtime characteristic of `O(n*m)`.
+    #[must_use]
+    #[inline]
+    pub fn is_submap<V2, A2: Allocator>(&self, other: &AssocList<K, V2, A2>) -> bool
+    where
+        K: PartialEq,
+        V: PartialEq<V2>,
+    {
+        self.vec
+            .iter()
+            .all(|(key, value)| other.get(key).is_some_and(|other_value| value == other_value))
+    }
+
+    /// Does the [`AssocList`] contain every key of `other`, associated with an equal value.
+    ///
+    /// This is the same as `other.is_submap(self)`, see [`is_submap`](AssocList::is_submap).
+    #[must_use]
+    #[inline]
+    pub fn is_superset<V2, A2: Allocator>(&self, other: &AssocList<K, V2, A2>) -> bool
+    where
+        K: PartialEq,
+        V2: PartialEq<V>,
+    {
+        other.is_submap(self)
+    }
+
+    /// Does `other` contain every key of the [`AssocList`]. The values are ignored.
+    ///
+    /// Returns at the first missing key, but has a runtime characteristic of `O(n*m)`.
+    #[must_use]
+    #[inline]
+    pub fn keys_subset_of<V2, A2: Allocator>(&self, other: &AssocList<K, V2, A2>) -> bool
+    where
+        K: PartialEq,
+    {
+        self.vec.iter().all(|(key, _value)| other.contains_key(key))
+    }
+
+    /// Does the [`AssocList`] contain every key of `other`. The values are ignored.
+    ///
+    /// This is the same as `other.keys_subset_of(self)`.
+    #[must_use]
+    #[inline]
+    pub fn keys_superset_of<V2, A2: Allocator>(&self, other: &AssocList<K, V2, A2>) -> bool
+    where
+        K: PartialEq,
+    {
+        other.keys_subset_of(self)
+    }
+
+    /// Do the [`AssocList`] and `other` contain no common key. The values are ignored.
+    ///
+    /// The keys of the smaller one are looked up in the larger one.
+    /// Returns at the first common key, but has a runtime characteristic of `O(n*m)`.
+    #[must_use]
+    #[inline]
+    pub fn keys_disjoint<V2, A2: Allocator>(&self, other: &AssocList<K, V2, A2>) -> bool
+    where
+        K: PartialEq,
+    {
+        if self.len() <= other.len() {
+            self.vec.iter().all(|(key, _value)| !other.contains_key(key))
+        } else {
+            other.vec.iter().all(|(key, _value)| !self.contains_key(key))
+        }
+    }
+
+    /// Inner join of the [`AssocList`] with `other` by key:
+    /// iterate over all keys contained in both, together with their values in both lists.
+    ///
+    /// The join is equivalent to [`intersection`](AssocList::intersection) and evaluated lazily
+    /// in the order of the [`AssocList`]. Looking up the keys in `other`
+    /// has a runtime characteristic of `O(n*m)`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use associated_list::{assoc_list, AssocList};
+    /// let measurements = assoc_list!((1, 20.5), (2, 21.0), (3, 19.5));
+    /// let calibration = assoc_list!((3, -0.5), (1, 0.5));
+    /// let joined: Vec<_> = measurements.join(&calibration).collect();
+    /// assert_eq!(joined, [(&1, &20.5, &0.5), (&3, &19.5, &-0.5)]);
+    /// ```
+    #[inline]
+    pub fn join<'a, V2, A2: Allocator>(
+        &'a self,
+        other: &'a AssocList<K, V2, A2>,
+    ) -> impl Iterator<Item = (&'a K, &'a V, &'a V2)>
+    where
+        K: PartialEq,
+    {
+        self.intersection(other)
+    }
+
+    /// Inner join of the [`AssocList`] with `other` by key, consuming both lists:
+    /// combine the values of all keys contained in both with `combine`,
+    /// in the order of the [`AssocList`].
+    ///
+    /// Since the keys are unique in the [`AssocList`], they are not checked for uniqueness again,
+    /// in contrast to collecting [`join`](AssocList::join).
+    /// Looking up the keys in `other` still has a runtime characteristic of `O(n*m)`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use associated_list::{assoc_list, AssocList};
+    /// let measurements = assoc_list!((1, 20.5), (2, 21.0), (3, 19.5));
+    /// let calibration = assoc_list!((3, -0.5), (1, 0.5));
+    /// let calibrated =
+    ///     measurements.join_map(calibration, |_sensor, value, offset| value + offset);
+    /// assert_eq!(calibrated, assoc_list!((1, 21.0), (3, 19.0)));
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn join_map<V2, W, A2: Allocator>(
+        self,
+        mut other: AssocList<K, V2, A2>,
+        mut combine: impl FnMut(&K, V, V2) -> W,
+    ) -> AssocList<K, W, A>
+    where
+        K: PartialEq,
+        A: Clone,
+    {
+        let mut vec = self.new_vec_with_capacity(self.len().min(other.len()));
+        for (key, value) in self.vec {
+            let Some(index) = other.vec.iter().position(|(other_key, _value)| other_key == &key)
+            else {
+                continue;
+            };
+            // the order of `other` is irrelevant, so removing joined keys speeds up later lookups
+            let (_other_key, other_value) = other.vec.swap_remove(index);
+            let new_value = combine(&key, value, other_value);
+            // keys of the AssocList are unique
+            vec.push((key, new_value));
+        }
+        AssocList { vec, phantom: PhantomData }
+    }
+
+    /// Remove the key-value pair with the smallest key from the [`AssocList`] and return it,
+    /// or [`None`] if it is empty.
+    ///
+    /// Like [`remove`](AssocList::remove), the order of the remaining elements is preserved.
+    #[must_use]
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        let (index, _min) =
+            self.vec.iter().enumerate().min_by_key(|&(_index, (key, _value))| key)?;
+        Some(self.vec.remove(index))
+    }
+
+    /// Remove the key-value pair with the largest key from the [`AssocList`] and return it,
+    /// or [`None`] if it is empty.
+    ///
+    /// Like [`remove`](AssocList::remove), the order of the remaining elements is preserved.
+    #[must_use]
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        let (index, _max) =
+            self.vec.iter().enumerate().max_by_key(|&(_index, (key, _value))| key)?;
+        Some(self.vec.remove(index))
+    }
+
+    /// Sort the key-value pairs of the [`AssocList`] with the comparator function `compare`.
+    ///
+    /// The sort is stable, i.e. equal elements keep their relative order.
+    ///
+    /// ```
+    /// use associated_list::{assoc_list, AssocList};
+    ///
+    /// let mut assoc_list = assoc_list!((2.5f32, 'b'), (-1.0, 'a'), (7.0, 'c'));
+    /// assoc_list.sort_by(|key, _value, other_key, _other_value| key.total_cmp(other_key));
+    /// assert!(assoc_list.keys().eq(&[-1.0, 2.5, 7.0]));
+    /// ```
+    #[inline]
+    pub fn sort_by(&mut self, mut compare: impl FnMut(&K, &V, &K, &V) -> Ordering) {
+        self.vec.sort_by(|(key, value), (other_key, other_value)| {
+            compare(key, value, other_key, other_value)
+        });
+    }
+
+    /// Sort the key-value pairs of the [`AssocList`] by the sort key extracted by `sort_key`.
+    ///
+    /// The sort is stable, i.e. elements with equal sort keys keep their relative order.
+    #[inline]
+    pub fn sort_by_key<T: Ord>(&mut self, mut sort_key: impl FnMut(&K, &V) -> T) {
+        self.vec.sort_by_key(|(key, value)| sort_key(key, value));
+    }
+
+    /// Sort the key-value pairs of the [`AssocList`] by their values.
+    ///
+    /// The sort is stable, i.e. elements with equal values keep their relative order.
+    #[inline]
+    pub fn sort_by_values(&mut self)
+    where
+        V: Ord,
+    {
+        self.vec.sort_by(|(_key, value), (_other_key, other_value)| value.cmp(other_value));
+    }
+
+    /// Sort the key-value pairs of the [`AssocList`] by their values.
+    ///
+    /// The sort is unstable, i.e. elements with equal values may be reordered.
+    #[inline]
+    pub fn sort_unstable_by_values(&mut self)
+    where
+        V: Ord,
+    {
+        self.vec
+            .sort_unstable_by(|(_key, value), (_other_key, other_value)| value.cmp(other_value));
+    }
+
+    /// Sort the key-value pairs with the comparator function `compare`,
+    /// returning a consuming iterator over the sorted elements.
+    ///
+    /// The sort is stable, i.e. equal elements keep their relative order.
+    /// No additional allocation is required.
+    #[inline]
+    pub fn sorted_by(
+        mut self,
+        compare: impl FnMut(&K, &V, &K, &V) -> Ordering,
+    ) -> <Self as IntoIterator>::IntoIter {
+        self.sort_by(compare);
+        self.into_iter()
+    }
+
+    /// Sort the key-value pairs with the comparator function `compare`,
+    /// returning a consuming iterator over the sorted elements.
+    ///
+    /// The sort is unstable, i.e. equal elements may be reordered.
+    /// No additional allocation is required.
+    #[inline]
+    pub fn sorted_unstable_by(
+        mut self,
+        mut compare: impl FnMut(&K, &V, &K, &V) -> Ordering,
+    ) -> <Self as IntoIterator>::IntoIter {
+        self.vec.sort_unstable_by(|(key, value), (other_key, other_value)| {
+            compare(key, value, other_key, other_value)
+        });
+        self.into_iter()
+    }
+
+    /// Insert a new element for the given `key` into an [`AssocList`] sorted by its keys,
+    /// returning the position of the element.
+    /// If the [`AssocList`] already contains an element associated with the key,
+    /// it is replaced and returned, and the element keeps its position.
+    /// Otherwise, the element is inserted at the position keeping the keys sorted.
+    ///
+    /// The key is searched using binary search.
+    /// If the [`AssocList`] is not sorted by its keys, the resulting position is unspecified
+    /// and the keys may no longer be unique.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn insert_sorted(&mut self, key: K, value: V) -> (usize, Option<V>)
+    where
+        K: Ord,
+    {
+        match self.binary_search_keys(&key) {
+            Ok(index) => {
+                let (_key, contained_value) =
+                    self.vec.get_mut(index).expect("Element has just been found!");
+                (index, Some(mem::replace(contained_value, value)))
+            },
+            Err(index) => {
+                self.vec.insert(index, (key, value));
+                (index, None)
+            },
+        }
+    }
+
+    /// Binary search an [`AssocList`] sorted by its keys for the `key`.
+    ///
+    /// Returns [`Ok`] with the position of the element associated with the `key`,
+    /// or [`Err`] with the position where it could be inserted while keeping the keys sorted.
+    /// If the [`AssocList`] is not sorted by its keys, the result is unspecified.
     ///
     /// ## Errors
-    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    /// If the [`AssocList`] doesn't contain an element associated with the `key`,
+    /// the position where it could be inserted is returned.
     #[inline]
-    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        self.vec.try_reserve_exact(additional)
+    pub fn binary_search_keys(&self, key: &K) -> Result<usize, usize>
+    where
+        K: Ord,
+    {
+        self.vec.binary_search_by(|(contained_key, _contained_value)| contained_key.cmp(key))
     }
 
-    /// Shrinks the capacity of the underlying [`Vec`] with a lower bound.
+    /// Binary search a sorted [`AssocList`] with the comparator function `compare`.
+    /// `compare` should return the ordering of the element relative to the searched target.
     ///
-    /// The capacity will remain at least as large as both the length and the supplied value.
+    /// Returns [`Ok`] with the position of a matching element,
+    /// or [`Err`] with the position where a matching element could be inserted
+    /// while keeping the [`AssocList`] sorted.
+    /// If the [`AssocList`] is not sorted with respect to `compare`, the result is unspecified.
     ///
-    /// If the current capacity is less than the lower limit, this is a no-op.
+    /// ## Errors
+    /// If no element matches, the position where it could be inserted is returned.
     #[inline]
-    pub fn shrink_to(&mut self, min_capacity: usize) {
-        self.vec.shrink_to(min_capacity);
+    pub fn binary_search_by(
+        &self,
+        mut compare: impl FnMut(&K, &V) -> Ordering,
+    ) -> Result<usize, usize> {
+        self.vec.binary_search_by(|(key, value)| compare(key, value))
     }
 
-    ///Shrinks the capacity of the underlying [`Vec`] as much as possible.
+    /// Return the position of the first element for which the `predicate` returns `false`,
+    /// assuming the [`AssocList`] is partitioned such that the `predicate` returns `true`
+    /// for all elements before those for which it returns `false`.
     ///
-    /// It will drop down as close as possible to the length but the allocator may still inform
-    /// the vector that there is space for a few more elements.
+    /// If the [`AssocList`] is not partitioned, the result is unspecified.
+    #[must_use]
     #[inline]
-    pub fn shrink_to_fit(&mut self) {
-        self.vec.shrink_to_fit();
+    pub fn partition_point(&self, mut predicate: impl FnMut(&K, &V) -> bool) -> usize {
+        self.vec.partition_point(|(key, value)| predicate(key, value))
+    }
+
+    /// Get a reference to the value associated with the `key` in an [`AssocList`]
+    /// sorted by its keys, using binary search.
+    ///
+    /// If the [`AssocList`] is not sorted by its keys, the result is unspecified.
+    /// In debug builds, this is checked with an assertion.
+    #[must_use]
+    #[inline]
+    pub fn get_sorted<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (_key, value) = self.vec.get(self.sorted_index_of(key)?)?;
+        Some(value)
+    }
+
+    /// Get a reference to the key-value pair associated with the `key` in an [`AssocList`]
+    /// sorted by its keys, using binary search.
+    ///
+    /// If the [`AssocList`] is not sorted by its keys, the result is unspecified.
+    /// In debug builds, this is checked with an assertion.
+    #[must_use]
+    #[inline]
+    pub fn get_sorted_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (contained_key, value) = self.vec.get(self.sorted_index_of(key)?)?;
+        Some((contained_key, value))
+    }
+
+    /// Get mutable access to the value associated with the `key` in an [`AssocList`]
+    /// sorted by its keys, using binary search.
+    ///
+    /// If the [`AssocList`] is not sorted by its keys, the result is unspecified.
+    /// In debug builds, this is checked with an assertion.
+    #[must_use]
+    #[inline]
+    pub fn get_sorted_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.sorted_index_of(key)?;
+        let (_key, value) = self.vec.get_mut(index)?;
+        Some(value)
+    }
+
+    /// Get the position of the element associated with the `key` using binary search.
+    ///
+    /// ## Panics
+    /// In debug builds, if the [`AssocList`] is not sorted by its keys.
+    fn sorted_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        debug_assert!(
+            self.vec.windows(2).all(|pair| matches!(
+                pair,
+                [(first_key, _first_value), (next_key, _next_value)]
+                    if first_key.borrow() <= next_key.borrow()
+            )),
+            "AssocList is not sorted by its keys"
+        );
+        self.vec
+            .binary_search_by(|(contained_key, _contained_value)| contained_key.borrow().cmp(key))
+            .ok()
+    }
+
+    /// Return an iterator over all key-value pairs in the order of their keys,
+    /// without modifying the [`AssocList`].
+    ///
+    /// The references are collected into a newly allocated [`Vec`] for sorting.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn iter_sorted(&self) -> IntoIter<(&K, &V)>
+    where
+        K: Ord,
+    {
+        self.iter_sorted_by(|key, _value, other_key, _other_value| key.cmp(other_key))
+    }
+
+    /// Return an iterator over all key-value pairs sorted by the comparator function `compare`,
+    /// without modifying the [`AssocList`].
+    ///
+    /// The sort is stable, i.e. equal elements keep their relative order.
+    /// The references are collected into a newly allocated [`Vec`] for sorting.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn iter_sorted_by(
+        &self,
+        mut compare: impl FnMut(&K, &V, &K, &V) -> Ordering,
+    ) -> IntoIter<(&K, &V)> {
+        let mut pairs: Vec<(&K, &V)> = self.vec.iter().map(|(key, value)| (key, value)).collect();
+        pairs.sort_by(|(key, value), (other_key, other_value)| {
+            compare(key, value, other_key, other_value)
+        });
+        pairs.into_iter()
+    }
+
+    /// Return an iterator over all keys in sorted order, without modifying the [`AssocList`].
+    ///
+    /// The references are collected into a newly allocated [`Vec`] for sorting.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn keys_sorted(&self) -> IntoIter<&K>
+    where
+        K: Ord,
+    {
+        let mut keys: Vec<&K> = self.keys().collect();
+        keys.sort_unstable();
+        keys.into_iter()
+    }
+
+    /// Return an iterator over all values in the order of their keys,
+    /// without modifying the [`AssocList`].
+    ///
+    /// The references are collected into a newly allocated [`Vec`] for sorting.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[must_use]
+    #[inline]
+    pub fn values_sorted_by_key(&self) -> IntoIter<&V>
+    where
+        K: Ord,
+    {
+        let mut pairs: Vec<&(K, V)> = self.vec.iter().collect();
+        pairs.sort_unstable_by(|(key, _value), (other_key, _other_value)| key.cmp(other_key));
+        let values: Vec<&V> = pairs.into_iter().map(|(_key, value)| value).collect();
+        values.into_iter()
+    }
+
+    /// Insert a new element for the given `key` without checking if the [`AssocList`]
+    /// already contains an element associated with it.
+    /// Returns references to the inserted key and value.
+    ///
+    /// This avoids the `O(n)` scan of [`insert`](AssocList::insert),
+    /// e.g. when inserting keys known to be unique, like those of another map.
+    ///
+    /// The method is safe, but inserting a key that is already contained breaks
+    /// the uniqueness invariant, resulting in unspecified (but memory safe) behavior
+    /// of other methods. In debug builds, this is checked with an assertion.
+    ///
+    /// ## Panics
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    #[inline]
+    pub fn insert_unique_unchecked(&mut self, key: K, value: V) -> (&K, &mut V)
+    where
+        K: PartialEq,
+    {
+        debug_assert!(
+            !self.vec.iter().any(|(contained_key, _contained_value)| contained_key == &key),
+            "AssocList already contains the key"
+        );
+        self.vec.push((key, value));
+        let (inserted_key, inserted_value) =
+            self.vec.last_mut().expect("Element has just been added!");
+        (inserted_key, inserted_value)
+    }
+
+    /// Insert all key-value pairs of `iter`, like [`extend`](Extend::extend),
+    /// but report allocation failures instead of panicking or aborting.
+    /// For duplicate keys, the later value is kept.
+    ///
+    /// ## Errors
+    /// If the capacity overflows, or the allocator reports a failure, then an error is returned.
+    /// The [`AssocList`] stays valid and contains all key-value pairs inserted up to this point.
+    #[inline]
+    pub fn try_extend<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryReserveError>
+    where
+        K: PartialEq,
+    {
+        let iter = iter.into_iter();
+        let (lower_bound, _upper_bound) = iter.size_hint();
+        self.vec.try_reserve(lower_bound)?;
+        for (key, value) in iter {
+            if let Some(contained_value) = self.get_mut(&key) {
+                *contained_value = value;
+            } else {
+                if self.len() == self.capacity() {
+                    self.vec.try_reserve(1)?;
+                }
+                self.vec.push((key, value));
+            }
+        }
+        Ok(())
+    }
+
+    /// Move the element at position `from` to position `to`, shifting all elements in between.
+    ///
+    /// ## Panics
+    ///
+    /// Programming error: if `from` or `to` is out-of-bounds.
+    fn shift_element(&mut self, from: usize, to: usize) {
+        if from < to {
+            self.vec.get_mut(from..=to).expect("Index out of bounds!").rotate_left(1);
+        } else {
+            self.vec.get_mut(to..=from).expect("Index out of bounds!").rotate_right(1);
+        }
+    }
+
+    /// Insert the key-value pair at the end of the [`AssocList`],
+    /// if it doesn't already contain the `key`.
+    /// Otherwise, replace the contained value with the result of `resolve`,
+    /// called with the contained key, the contained and the new value.
+    fn insert_or_resolve(&mut self, key: K, value: V, resolve: impl FnOnce(&K, V, V) -> V)
+    where
+        K: PartialEq,
+    {
+        let Some(index) = self.get_index_of(&key) else {
+            self.vec.push((key, value));
+            return;
+        };
+        // temporarily move the contained element to the end, to take ownership of its value
+        let (contained_key, contained_value) = self.vec.swap_remove(index);
+        let resolved_value = resolve(&contained_key, contained_value, value);
+        self.vec.push((contained_key, resolved_value));
+        let last_index = self.len().saturating_sub(1);
+        self.vec.swap(index, last_index);
+    }
+
+    #[cfg(feature = "allocator_api")]
+    /// Create a new, empty [`Vec`] with at least the specified `capacity`,
+    /// using the same allocator as the [`AssocList`].
+    fn new_vec_with_capacity<T>(&self, capacity: usize) -> Vec<T, A>
+    where
+        A: Clone,
+    {
+        Vec::with_capacity_in(capacity, self.vec.allocator().clone())
+    }
+
+    #[cfg(not(feature = "allocator_api"))]
+    /// Create a new, empty [`Vec`] with at least the specified `capacity`,
+    /// using the same allocator as the [`AssocList`].
+    #[allow(clippy::unused_self)]
+    fn new_vec_with_capacity<T>(&self, capacity: usize) -> Vec<T>
+    where
+        A: Clone,
+    {
+        Vec::with_capacity(capacity)
     }
 }
 
@@ -501,7 +2949,48 @@ impl<K: PartialEq, V, const N: usize> From<[(K, V); N]> for AssocList<K, V> {
     }
 }
 
-impl<Q: PartialEq, K: Borrow<Q>, V, A: Allocator> Index<Q> for AssocList<K, V, A> {
+impl<K: PartialEq + Clone, V: Clone> From<&[(K, V)]> for AssocList<K, V> {
+    #[inline]
+    fn from(slice: &[(K, V)]) -> Self {
+        let mut assoc_list = AssocList::with_capacity(slice.len());
+        for (key, value) in slice {
+            // Bei wiederholten Schlüssel werden frühere Werte überschrieben.
+            let _ = assoc_list.insert(key.clone(), value.clone());
+        }
+        assoc_list
+    }
+}
+
+impl<K: PartialEq, V> TryFrom<Vec<(K, V)>> for AssocList<K, V> {
+    type Error = DuplicateKeyError<K>;
+
+    /// Create an [`AssocList`] from a [`Vec`], reusing its allocation.
+    ///
+    /// In contrast to the [`FromIterator`] and [`From`] implementations,
+    /// duplicate keys are rejected instead of keeping the later value.
+    /// For arrays, use [`try_from_array`](AssocList::try_from_array).
+    ///
+    /// ## Errors
+    /// Returns a [`DuplicateKeyError`] for the first element with a key, that already occurred
+    /// at a smaller index. The error contains the indices of both occurrences.
+    /// The [`Vec`] is consumed, all other key-value pairs are dropped.
+    #[inline]
+    fn try_from(mut vec: Vec<(K, V)>) -> Result<Self, Self::Error> {
+        let duplicate_indices = vec.iter().enumerate().find_map(|(index, (key, _value))| {
+            vec.iter()
+                .take(index)
+                .position(|(previous_key, _previous_value)| previous_key == key)
+                .map(|first_index| (index, first_index))
+        });
+        if let Some((index, first_index)) = duplicate_indices {
+            let (key, _value) = vec.swap_remove(index);
+            return Err(DuplicateKeyError { key, index, first_index });
+        }
+        Ok(AssocList { vec, phantom: PhantomData })
+    }
+}
+
+impl<Q: Equivalent<K>, K, V, A: Allocator> Index<Q> for AssocList<K, V, A> {
     type Output = V;
 
     #[inline]
@@ -510,13 +2999,72 @@ impl<Q: PartialEq, K: Borrow<Q>, V, A: Allocator> Index<Q> for AssocList<K, V, A
     }
 }
 
-impl<Q: PartialEq, K: Borrow<Q>, V, A: Allocator> IndexMut<Q> for AssocList<K, V, A> {
+impl<Q: Equivalent<K>, K, V, A: Allocator> IndexMut<Q> for AssocList<K, V, A> {
     #[inline]
     fn index_mut(&mut self, key: Q) -> &mut Self::Output {
         self.get_mut(&key).expect("Unknown key")
     }
 }
 
+impl<K, V, A, A2> BitOr<&AssocList<K, V, A2>> for &AssocList<K, V, A>
+where
+    K: PartialEq + Clone,
+    V: Clone,
+    A: Allocator + Clone,
+    A2: Allocator,
+{
+    type Output = AssocList<K, V, A>;
+
+    /// Create a new [`AssocList`] with the key-value pairs of [`union`](AssocList::union),
+    /// i.e. the values of `self` take precedence.
+    #[inline]
+    fn bitor(self, other: &AssocList<K, V, A2>) -> Self::Output {
+        let mut vec = self.new_vec_with_capacity(self.len().saturating_add(other.len()));
+        vec.extend(self.union(other).map(|(key, value)| (key.clone(), value.clone())));
+        AssocList { vec, phantom: PhantomData }
+    }
+}
+
+impl<K, V, V2, A, A2> BitAnd<&AssocList<K, V2, A2>> for &AssocList<K, V, A>
+where
+    K: PartialEq + Clone,
+    V: Clone,
+    A: Allocator + Clone,
+    A2: Allocator,
+{
+    type Output = AssocList<K, V, A>;
+
+    /// Create a new [`AssocList`] with the key-value pairs of `self`
+    /// with a key contained in `other`, see [`intersection`](AssocList::intersection).
+    #[inline]
+    fn bitand(self, other: &AssocList<K, V2, A2>) -> Self::Output {
+        let mut vec = self.new_vec_with_capacity(self.len().min(other.len()));
+        vec.extend(
+            self.intersection(other).map(|(key, value, _other_value)| (key.clone(), value.clone())),
+        );
+        AssocList { vec, phantom: PhantomData }
+    }
+}
+
+impl<K, V, V2, A, A2> Sub<&AssocList<K, V2, A2>> for &AssocList<K, V, A>
+where
+    K: PartialEq + Clone,
+    V: Clone,
+    A: Allocator + Clone,
+    A2: Allocator,
+{
+    type Output = AssocList<K, V, A>;
+
+    /// Create a new [`AssocList`] with the key-value pairs of [`difference`](AssocList::difference),
+    /// i.e. the key-value pairs of `self` with a key not contained in `other`.
+    #[inline]
+    fn sub(self, other: &AssocList<K, V2, A2>) -> Self::Output {
+        let mut vec = self.new_vec_with_capacity(self.len());
+        vec.extend(self.difference(other).map(|(key, value)| (key.clone(), value.clone())));
+        AssocList { vec, phantom: PhantomData }
+    }
+}
+
 impl<K, V, A: Allocator> IntoIterator for AssocList<K, V, A> {
     type Item = (K, V);
 
@@ -553,6 +3101,42 @@ impl<'a, K, V, A: Allocator> IntoIterator for &'a mut AssocList<K, V, A> {
     }
 }
 
+impl<K: PartialEq, V, A: Allocator> FromIterator<AssocList<K, V, A>> for AssocList<K, V> {
+    /// Merge all [`AssocLists`](AssocList) into a single one.
+    /// Key-value pairs of later lists override earlier ones, as with repeated [`Extend::extend`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use associated_list::{assoc_list, AssocList};
+    /// let shards = [
+    ///     assoc_list!(("a", 1), ("b", 1)),
+    ///     assoc_list!(("b", 2), ("c", 2)),
+    ///     assoc_list!(("c", 3), ("d", 3)),
+    /// ];
+    /// let merged: AssocList<_, _> = shards.into_iter().collect();
+    /// assert_eq!(merged, assoc_list!(("a", 1), ("b", 2), ("c", 3), ("d", 3)));
+    /// ```
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = AssocList<K, V, A>>>(iter: T) -> Self {
+        let shards: Vec<_> = iter.into_iter().collect();
+        let capacity = shards.iter().fold(0, |acc: usize, shard| acc.saturating_add(shard.len()));
+        let mut assoc_list = AssocList::with_capacity(capacity);
+        for shard in shards {
+            assoc_list.extend(shard);
+        }
+        assoc_list
+    }
+}
+
+impl<K: PartialEq, V, A: Allocator> Sum<AssocList<K, V, A>> for AssocList<K, V> {
+    /// Merge all [`AssocLists`](AssocList) into a single one,
+    /// equivalent to [`collect`](Iterator::collect)ing them.
+    #[inline]
+    fn sum<I: Iterator<Item = AssocList<K, V, A>>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
 impl<K: PartialEq, V> FromIterator<(K, V)> for AssocList<K, V> {
     #[inline]
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
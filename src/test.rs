@@ -1,20 +1,24 @@
 //! Unit tests for an [`AssocList`].
 
-use core::ops::IndexMut;
+use core::{
+    cell::Cell,
+    ops::{Bound, IndexMut},
+};
 
 use alloc::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     vec::Vec,
 };
 
 use quickcheck_macros::quickcheck;
 
-use crate::{assoc_list, Allocator, AssocList};
+use crate::{assoc_list, entry::IndexedEntry, error::InsertError, Allocator, AssocList, CasError};
 
 #[cfg(feature = "allocator_api")]
 mod allocator;
 mod entry;
+mod equivalent;
 mod iter;
 
 // O(n*log(n))
@@ -29,6 +33,40 @@ fn split_tuple_refs<A, B>((key, value): &(A, B)) -> (&A, &B) {
     (key, value)
 }
 
+/// Value type tracking the number of currently alive instances.
+#[derive(Debug)]
+struct DropCounter<'c>(&'c Cell<usize>);
+
+impl<'c> DropCounter<'c> {
+    /// Create a new [`DropCounter`], incrementing the number of `alive` instances.
+    fn new(alive: &'c Cell<usize>) -> Self {
+        alive.set(alive.get().saturating_add(1));
+        DropCounter(alive)
+    }
+}
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get().saturating_sub(1));
+    }
+}
+
+/// Key type counting the number of comparisons.
+#[derive(Debug)]
+struct ComparisonCounter<'c> {
+    /// The identity of the key.
+    id: usize,
+    /// The number of comparisons done by all keys sharing the [`Cell`].
+    comparisons: &'c Cell<usize>,
+}
+
+impl PartialEq for ComparisonCounter<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.comparisons.set(self.comparisons.get().saturating_add(1));
+        self.id == other.id
+    }
+}
+
 #[test]
 fn new() {
     const ASSOC_LIST: AssocList<usize, f64> = AssocList::new();
@@ -36,6 +74,23 @@ fn new() {
     assert!(unique_ord_keys(ASSOC_LIST));
 }
 
+#[test]
+fn try_with_capacity() {
+    const CAPACITY: usize = 5;
+    let assoc_list: Result<AssocList<u8, u8>, _> = AssocList::try_with_capacity(CAPACITY);
+    assert!(assoc_list.is_ok_and(|list| list.is_empty() && list.capacity() >= CAPACITY));
+    let too_large: Result<AssocList<u64, u64>, _> = AssocList::try_with_capacity(usize::MAX);
+    assert!(too_large.is_err(), "capacity overflow is reported");
+}
+
+#[test]
+fn try_from_iter() {
+    let assoc_list = AssocList::try_from_iter([(1, 'a'), (2, 'b'), (1, 'c')]);
+    assert_eq!(assoc_list, Ok(assoc_list!((1, 'c'), (2, 'b'))), "later values are kept");
+    let too_large = AssocList::try_from_iter((0..usize::MAX).map(|index| (index, 'a')));
+    assert!(too_large.is_err(), "capacity overflow is reported");
+}
+
 #[test]
 fn default() {
     let assoc_list: AssocList<i32, &'static str> = AssocList::default();
@@ -104,13 +159,41 @@ fn from() {
     );
 }
 
+#[test]
+fn from_slice() {
+    macro_rules! test_from_slice {
+        ($([$($input: tt)*]),* $(,)?) => {$(
+            let reference_map = BTreeMap::from([$($input)*]);
+            let mut reference_map_vec: Vec<(i32, i32)> = reference_map.into_iter().collect();
+            let slice: &[(i32, i32)] = &[$($input)*];
+            let assoc_list = AssocList::from(slice);
+            let mut assoc_list_vec = assoc_list.vec.clone();
+            // check keys are unique
+            assert!(unique_ord_keys(assoc_list));
+            // check the result is identical to a BTreeMap
+            reference_map_vec.sort();
+            assoc_list_vec.sort();
+            assert_eq!(assoc_list_vec, reference_map_vec);
+        )*};
+    }
+
+    test_from_slice!(
+        [],                                                            // empty
+        [(3, 7), (8, -1), (9, 0), (0, 4)],                             // unique keys
+        [(-3, 7), (-3, -1), (9, 0), (0, 4)],                           // negative keys
+        [(3, 7), (8, -1), (3, 0), (0, 4)],                             // duplicated key
+        [(8, -1), (3, 0), (0, 4), (-8, 1), (8, 2), (0, 1), (-8, 267)], // duplicated & negative keys
+    );
+}
+
 #[test]
 fn from_iterator() {
     macro_rules! test_collect {
         ($([$($input: tt)*]),* $(,)?) => {$(
             let reference_map = BTreeMap::from([$($input)*]);
             let mut reference_map_vec: Vec<(i32, i32)> = reference_map.into_iter().collect();
-            let assoc_list: AssocList<i32, i32> = [$($input)*].into_iter().collect();
+            let assoc_list: AssocList<i32, i32> =
+                Vec::<(i32, i32)>::from([$($input)*]).into_iter().collect();
             let mut assoc_list_vec = assoc_list.vec.clone();
             // check keys are unique
             assert!(unique_ord_keys(assoc_list));
@@ -342,6 +425,46 @@ fn remove_entry() {
     assert!(assoc_list.vec.contains(&(ANOTHER, ANOTHER_VALUE)), "other value untouched");
 }
 
+#[test]
+fn remove_order() {
+    let mut shifted = assoc_list!(("a", 1), ("b", 2), ("c", 3), ("d", 4));
+    let mut swapped = shifted.clone();
+
+    assert_eq!(shifted.remove("a"), Some(1));
+    assert_eq!(shifted.remove_entry("c"), Some(("c", 3)));
+    assert_eq!(shifted.vec, Vec::from([("b", 2), ("d", 4)]), "order is preserved");
+
+    assert_eq!(swapped.swap_remove("a"), Some(1));
+    assert_eq!(swapped.vec, Vec::from([("d", 4), ("b", 2), ("c", 3)]), "last element moved");
+    assert_eq!(swapped.swap_remove_entry("d"), Some(("d", 4)));
+    assert_eq!(swapped.vec, Vec::from([("c", 3), ("b", 2)]), "last element moved");
+    assert_eq!(swapped.swap_remove("missing"), None);
+    assert_eq!(swapped.swap_remove_entry("missing"), None);
+}
+
+#[test]
+fn remove_if() {
+    let mut sessions = assoc_list!(("alice", "active"), ("bob", "expired"), ("carol", "expired"));
+    assert_eq!(sessions.remove_if("alice", |_key, state| *state == "expired"), None);
+    assert_eq!(sessions.len(), 3, "predicate false leaves the AssocList untouched");
+    assert_eq!(sessions.remove_if("bob", |_key, state| *state == "expired"), Some("expired"));
+    assert_eq!(sessions.remove_if("missing", |_key, _state| true), None);
+    assert_eq!(sessions.vec, Vec::from([("alice", "active"), ("carol", "expired")]));
+}
+
+#[test]
+fn remove_by_value() {
+    let mut assoc_list = assoc_list!(("a", 1), ("b", 0), ("c", 2), ("d", 0));
+    assert_eq!(assoc_list.remove_by_value(&0), Some(("b", 0)), "first match");
+    assert_eq!(assoc_list.vec, Vec::from([("a", 1), ("c", 2), ("d", 0)]), "only first match");
+    assert_eq!(assoc_list.remove_by_value(&0), Some(("d", 0)));
+    assert_eq!(assoc_list.remove_by_value(&0), None);
+
+    let mut strings = assoc_list!((1, String::from("one")), (2, String::from("two")));
+    assert_eq!(strings.remove_by_value("two"), Some((2, String::from("two"))));
+    assert_eq!(strings.len(), 1);
+}
+
 #[test]
 fn reserve() {
     const ADDITIONAL: usize = 64;
@@ -517,3 +640,1523 @@ fn index_mut(reference_map: BTreeMap<String, f32>) {
         }
     }));
 }
+
+#[test]
+fn try_map_values() {
+    let numbers = assoc_list!((3, 7), (-1, 2), (8, -5));
+    let products: Result<_, ()> = numbers.try_map_values(|key, value| Ok(key * value));
+    assert_eq!(products.map(|mapped| mapped.vec), Ok(Vec::from([(3, 21), (-1, -2), (8, -40)])));
+
+    let alive = Cell::new(0);
+    let counters = assoc_list!(
+        ("first", DropCounter::new(&alive)),
+        ("second", DropCounter::new(&alive)),
+        ("third", DropCounter::new(&alive)),
+    );
+    let result = counters.try_map_values(|key, _value| {
+        if *key == "second" {
+            Err("conversion failed")
+        } else {
+            Ok(DropCounter::new(&alive))
+        }
+    });
+    assert_eq!(result.err(), Some("conversion failed"));
+    assert_eq!(alive.get(), 0, "all old and converted values are dropped");
+}
+
+#[test]
+fn map_values() {
+    let comparisons = Cell::new(0);
+    let mut raw = AssocList::new();
+    for id in 0..4 {
+        let _ = raw.insert(ComparisonCounter { id, comparisons: &comparisons }, id.to_string());
+    }
+    comparisons.set(0);
+    let parsed = raw.map_values(|key, value| value.parse::<usize>().map(|number| number * key.id));
+    assert_eq!(comparisons.get(), 0, "keys are not compared again");
+    let parsed_pairs: Vec<_> = parsed.into_iter().map(|(key, value)| (key.id, value)).collect();
+    assert_eq!(parsed_pairs, Vec::from([(0, Ok(0)), (1, Ok(1)), (2, Ok(4)), (3, Ok(9))]));
+}
+
+#[test]
+fn map_keys() {
+    let headers = assoc_list!(
+        ("Accept", String::from("text/html")),
+        ("Host", String::from("example.org")),
+        ("ACCEPT", String::from("*/*")),
+        ("accept", String::from("text/plain")),
+        ("Content-Type", String::from("text/plain")),
+    );
+    let mut resolved_keys = Vec::new();
+    let normalized = headers.map_keys(str::to_ascii_lowercase, |key, mut combined, later| {
+        resolved_keys.push(key.clone());
+        combined.push_str(", ");
+        combined.push_str(&later);
+        combined
+    });
+    assert_eq!(
+        normalized.vec,
+        Vec::from([
+            (String::from("accept"), String::from("text/html, */*, text/plain")),
+            (String::from("host"), String::from("example.org")),
+            (String::from("content-type"), String::from("text/plain")),
+        ]),
+        "combined values keep the position of the first occurrence"
+    );
+    assert_eq!(resolved_keys, Vec::from(["accept", "accept"]));
+}
+
+#[test]
+fn try_retain() {
+    let mut assoc_list = assoc_list!((1, 10), (2, 20), (3, 30), (4, 40));
+    let result: Result<(), ()> = assoc_list.try_retain(|key, value| {
+        *value += 1;
+        Ok(key % 2 == 0)
+    });
+    assert_eq!(result, Ok(()));
+    assert_eq!(assoc_list.vec, Vec::from([(2, 21), (4, 41)]));
+
+    let mut partial = assoc_list!((1, 10), (2, 20), (3, 30), (4, 40), (5, 50));
+    let error = partial.try_retain(|key, value| {
+        if *key == 4 {
+            return Err("invalid checksum");
+        }
+        *value += 1;
+        Ok(key % 2 == 0)
+    });
+    assert_eq!(error, Err("invalid checksum"));
+    assert_eq!(
+        partial.vec,
+        Vec::from([(2, 21), (4, 40), (5, 50)]),
+        "visited elements are processed, the rest remains untouched"
+    );
+}
+
+#[test]
+fn move_entry_to() {
+    let mut staging = assoc_list!(("a", 1), ("b", 2), ("c", 3));
+    let mut live = assoc_list!(("x", 10), ("b", 20));
+
+    assert!(staging.move_entry_to("a", &mut live), "existing key is moved");
+    assert!(staging.move_entry_to("b", &mut live), "key already in destination is moved");
+    assert!(!staging.move_entry_to("unknown", &mut live), "unknown key is not moved");
+
+    assert_eq!(staging.vec, Vec::from([("c", 3)]));
+    assert_eq!(live.vec, Vec::from([("x", 10), ("b", 2), ("a", 1)]), "moved value wins");
+}
+
+#[test]
+fn append() {
+    let mut assoc_list = assoc_list!(("a", 1), ("b", 2));
+    let mut other = assoc_list!(("c", 3), ("a", 10), ("d", 4));
+    assoc_list.append(&mut other);
+    assert!(other.is_empty(), "other is left empty");
+    assert_eq!(assoc_list.vec, Vec::from([("a", 10), ("b", 2), ("c", 3), ("d", 4)]));
+
+    assoc_list.append(&mut other);
+    assert_eq!(assoc_list.len(), 4, "appending an empty AssocList has no effect");
+}
+
+#[test]
+fn extend_with_merge() {
+    let mut defaults =
+        assoc_list!(("plugins", Vec::from(["core"])), ("theme", Vec::from(["dark"])));
+    let user = assoc_list!(
+        ("font", Vec::from(["mono"])),
+        ("plugins", Vec::from(["git", "lsp"])),
+        ("keymap", Vec::from(["vim"])),
+    );
+    let mut resolved_keys = Vec::new();
+    defaults.extend_with(user, |key, plugins, user_plugins| {
+        resolved_keys.push(*key);
+        plugins.extend(user_plugins);
+    });
+    assert_eq!(resolved_keys, Vec::from(["plugins"]), "only called for contained keys");
+    assert_eq!(
+        defaults.vec,
+        Vec::from([
+            ("plugins", Vec::from(["core", "git", "lsp"])),
+            ("theme", Vec::from(["dark"])),
+            ("font", Vec::from(["mono"])),
+            ("keymap", Vec::from(["vim"])),
+        ]),
+        "contained keys keep their position, new keys are appended in order"
+    );
+}
+
+#[test]
+fn extend_with() {
+    let mut totals = assoc_list!(("b", 1));
+    let events = [("a", 5), ("b", 2), ("a", 3), ("c", 0), ("b", 4)];
+    totals.extend_with(events, |_key, total, amount| *total += amount);
+    assert_eq!(totals.vec, Vec::from([("b", 7), ("a", 8), ("c", 0)]));
+    assert!(totals.capacity() >= events.len(), "reserved from the size hint");
+}
+
+#[test]
+fn extend_unique() {
+    let mut fields = assoc_list!(("id", 1));
+    assert_eq!(fields.extend_unique([("name", 2), ("size", 3)]), Ok(()));
+
+    let error = fields
+        .extend_unique([("date", 4), ("name", 5), ("owner", 6)])
+        .expect_err("name is already contained");
+    assert_eq!((*error.key(), error.index(), error.first_index()), ("name", 1, 1));
+    assert_eq!(fields.vec, Vec::from([("id", 1), ("name", 2), ("size", 3), ("date", 4)]));
+
+    let error_within_iter =
+        fields.extend_unique([("mode", 7), ("mode", 8)]).expect_err("mode is duplicated");
+    assert_eq!(error_within_iter.into_key(), "mode");
+    assert_eq!(fields.get("mode"), Some(&7), "already inserted elements stay");
+}
+
+#[test]
+fn prepend() {
+    let mut assoc_list = assoc_list!(("old", 1), ("shared", 2), ("another old", 3));
+    let mut other = assoc_list!(("new", 10), ("shared", 20));
+
+    assoc_list.prepend(&mut other);
+
+    assert!(other.is_empty(), "all elements are moved");
+    assert_eq!(
+        assoc_list.vec,
+        Vec::from([("new", 10), ("shared", 20), ("old", 1), ("another old", 3)]),
+        "prepended elements come first and take precedence"
+    );
+}
+
+// signal a mismatch of the result
+#[allow(clippy::panic)]
+#[test]
+fn try_from_vec() {
+    macro_rules! test_try_from {
+        ($([$($input: tt)*] => $duplicate: expr),* $(,)?) => {$(
+            let input: Vec<(i32, i32)> = Vec::from([$($input)*]);
+            let duplicate: Option<(i32, usize, usize)> = $duplicate;
+            assert_eq!(
+                AssocList::try_from_array([$($input)*]).map(|assoc_list| assoc_list.vec),
+                AssocList::try_from(input.clone()).map(|assoc_list| assoc_list.vec),
+            );
+            match (AssocList::try_from(input), duplicate) {
+                (Ok(assoc_list), None) => {
+                    let mut reference_map_vec: Vec<_> =
+                        BTreeMap::from([$($input)*]).into_iter().collect();
+                    let mut assoc_list_vec = assoc_list.vec.clone();
+                    // check keys are unique
+                    assert!(unique_ord_keys(assoc_list));
+                    // check the result is identical to a BTreeMap
+                    reference_map_vec.sort();
+                    assoc_list_vec.sort();
+                    assert_eq!(assoc_list_vec, reference_map_vec);
+                },
+                (Err(error), Some((key, index, first_index))) => {
+                    assert_eq!(*error.key(), key);
+                    assert_eq!(error.index(), index);
+                    assert_eq!(error.first_index(), first_index);
+                },
+                (result, expected) => panic!("{result:?} doesn't match {expected:?}"),
+            }
+        )*};
+    }
+
+    test_try_from!(
+        // empty
+        [] => None,
+        // unique keys
+        [(3, 7), (8, -1), (9, 0), (0, 4)] => None,
+        // negative keys
+        [(-3, 7), (-3, -1), (9, 0), (0, 4)] => Some((-3, 1, 0)),
+        // duplicated key
+        [(3, 7), (8, -1), (3, 0), (0, 4)] => Some((3, 2, 0)),
+        // duplicated & negative keys
+        [(8, -1), (3, 0), (0, 4), (-8, 1), (8, 2), (0, 1), (-8, 267)] => Some((8, 4, 0)),
+    );
+}
+
+#[test]
+fn upsert() {
+    let mut assoc_list = assoc_list!(("occupied", 1));
+    let inserts = Cell::new(0);
+    let updates = Cell::new(0);
+    let insert = || {
+        inserts.set(inserts.get() + 1);
+        10
+    };
+    let update = |value: &mut i32| {
+        updates.set(updates.get() + 1);
+        *value += 1;
+    };
+
+    assert_eq!(*assoc_list.upsert("occupied", insert, update), 2);
+    assert_eq!((inserts.get(), updates.get()), (0, 1), "only update is called");
+
+    assert_eq!(*assoc_list.upsert("vacant", insert, update), 10);
+    assert_eq!((inserts.get(), updates.get()), (1, 1), "only insert is called");
+
+    *assoc_list.upsert("vacant", insert, update) *= 2;
+    assert_eq!(assoc_list.vec, Vec::from([("occupied", 2), ("vacant", 22)]));
+}
+
+#[test]
+fn group_values_by() {
+    let assoc_list =
+        assoc_list!(("apple", 1), ("banana", 2), ("avocado", 3), ("blueberry", 4), ("cherry", 5));
+    let groups = assoc_list.group_values_by(|key, _value| key.chars().next());
+    assert_eq!(
+        groups.vec,
+        Vec::from([
+            (Some('a'), Vec::from([("apple", 1), ("avocado", 3)])),
+            (Some('b'), Vec::from([("banana", 2), ("blueberry", 4)])),
+            (Some('c'), Vec::from([("cherry", 5)])),
+        ])
+    );
+}
+
+#[test]
+fn dedup_by_value() {
+    let mut endpoints = assoc_list!(
+        ("first", "10.0.0.1:80"),
+        ("second", "10.0.0.2:80"),
+        ("third", "10.0.0.1:443"),
+        ("fourth", "10.0.0.3:80"),
+        ("fifth", "10.0.0.2:8080"),
+    );
+    let removed = endpoints.dedup_by_value(|address| address.split(':').next());
+    assert_eq!(removed, 2);
+    assert_eq!(
+        endpoints.vec,
+        Vec::from([("first", "10.0.0.1:80"), ("second", "10.0.0.2:80"), ("fourth", "10.0.0.3:80")]),
+        "the first key of each projection is kept"
+    );
+
+    let mut unique = assoc_list!((1, 'a'), (2, 'b'), (3, 'c'));
+    assert_eq!(unique.dedup_by_value(|value| *value), 0, "all-unique projections");
+    assert_eq!(unique.vec, Vec::from([(1, 'a'), (2, 'b'), (3, 'c')]));
+
+    let mut identical = assoc_list!((1, 'a'), (2, 'b'), (3, 'c'));
+    assert_eq!(identical.dedup_by_value(|_value| ()), 2, "all-identical projections");
+    assert_eq!(identical.vec, Vec::from([(1, 'a')]));
+}
+
+#[test]
+fn invert_grouped() {
+    let assoc_list = assoc_list!(("a", 1), ("b", 2), ("c", 1), ("d", 3), ("e", 1));
+    let inverted = assoc_list.invert_grouped();
+    assert_eq!(
+        inverted.vec,
+        Vec::from([(1, Vec::from(["a", "c", "e"])), (2, Vec::from(["b"])), (3, Vec::from(["d"]))])
+    );
+}
+
+#[test]
+fn invert() {
+    let names = assoc_list!((1, "one"), (2, "two"), (3, "one"), (4, "four"));
+    assert_eq!(
+        names.clone().invert().vec,
+        Vec::from([("one", 3), ("two", 2), ("four", 4)]),
+        "the later key is kept at the position of the first occurrence"
+    );
+    assert_eq!(
+        names.invert_with(|_value, earlier, later| earlier.min(later)).vec,
+        Vec::from([("one", 1), ("two", 2), ("four", 4)])
+    );
+}
+
+#[test]
+fn min_max_by_value() {
+    let scores = assoc_list!(
+        ("nan", f32::NAN),
+        ("low", -3.5),
+        ("another nan", f32::NAN),
+        ("high", 7.25),
+        ("also high", 7.25),
+        ("also low", -3.5),
+    );
+    assert_eq!(scores.min_by_value(), Some((&"low", &-3.5)), "NaN is skipped");
+    assert_eq!(scores.max_by_value(), Some((&"high", &7.25)), "NaN is skipped");
+    assert_eq!(assoc_list!((0, f32::NAN)).max_by_value(), None, "only incomparable values");
+    assert_eq!(AssocList::<u8, f32>::new().min_by_value(), None);
+
+    let lengths = assoc_list!((1, "short"), (2, "longer"), (3, "tiny"), (4, "lengthy"));
+    let compare_len = |value: &&str, other: &&str| value.len().cmp(&other.len());
+    assert_eq!(lengths.min_by_value_by(compare_len), Some((&3, &"tiny")));
+    assert_eq!(lengths.max_by_value_by(compare_len), Some((&4, &"lengthy")));
+    assert_eq!(AssocList::<u8, &str>::new().max_by_value_by(compare_len), None);
+}
+
+#[test]
+fn min_max_key_value() {
+    let markers = assoc_list!(
+        (f32::NAN, "nan"),
+        (2.5f32, "middle"),
+        (-1.0, "low"),
+        (f32::NAN, "another nan"),
+        (8.0, "high"),
+    );
+    assert_eq!(markers.min_key_value(), Some((&-1.0, &"low")), "NaN is skipped");
+    assert_eq!(markers.max_key_value(), Some((&8.0, &"high")), "NaN is skipped");
+    assert_eq!(assoc_list!((f32::NAN, 0)).min_key_value(), None, "only incomparable keys");
+    assert_eq!(AssocList::<f32, u8>::new().max_key_value(), None);
+
+    let words = assoc_list!(("short", 1), ("longer", 2), ("tiny", 3), ("amount", 4));
+    let key_len = |key: &&str, _value: &i32| key.len();
+    assert_eq!(words.min_by_key(key_len), Some((&"tiny", &3)));
+    assert_eq!(words.max_by_key(key_len), Some((&"longer", &2)), "first maximum is returned");
+    let score = |_key: &&str, value: &i32| if *value == 1 { f32::NAN } else { 0.25 };
+    assert_eq!(words.min_by_key(score), Some((&"longer", &2)), "NaN projections are skipped");
+}
+
+#[test]
+fn truncate() {
+    let mut assoc_list = assoc_list!((1, 'a'), (2, 'b'), (3, 'c'));
+    let snapshot = assoc_list.clone();
+    let snapshot_len = assoc_list.len();
+    assoc_list.truncate(5);
+    assert_eq!(assoc_list, snapshot, "no-op for len > self.len()");
+    let _ = assoc_list.insert(4, 'd');
+    let _ = assoc_list.insert(5, 'e');
+    assoc_list.truncate(snapshot_len);
+    assert_eq!(assoc_list.vec, snapshot.vec, "the surviving prefix is the original one");
+    assoc_list.truncate(0);
+    assert!(assoc_list.is_empty(), "len == 0 removes all elements");
+}
+
+#[test]
+fn keep_last() {
+    let mut history = assoc_list!((1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e'));
+    history.keep_last(7);
+    assert_eq!(history.len(), 5, "no-op for n > len");
+    history.keep_last(5);
+    assert_eq!(history.len(), 5, "no-op for n == len");
+    history.keep_last(2);
+    assert_eq!(history.vec, Vec::from([(4, 'd'), (5, 'e')]), "newest elements are kept");
+    history.keep_last(0);
+    assert!(history.is_empty(), "n == 0 removes all elements");
+}
+
+#[test]
+fn compare_and_swap() {
+    let mut assoc_list = assoc_list!(("key", 1), ("another", 2));
+
+    assert_eq!(assoc_list.compare_and_swap("key", &1, 10), Ok(1), "expected value matches");
+    assert_eq!(assoc_list.compare_and_swap("key", &1, 20), Err(CasError::Mismatch(20)));
+    assert_eq!(assoc_list.compare_and_swap("missing", &1, 30), Err(CasError::MissingKey(30)));
+    assert_eq!(assoc_list.vec, Vec::from([("key", 10), ("another", 2)]));
+}
+
+#[test]
+fn error_display() {
+    let duplicate_error =
+        AssocList::try_from(Vec::from([(1, 'a'), (2, 'b'), (2, 'c')])).expect_err("duplicate key");
+    assert_eq!(
+        duplicate_error.to_string(),
+        "duplicate key at index 2, first occurrence at index 1"
+    );
+    assert_eq!(
+        CasError::Mismatch(0).to_string(),
+        "the current value doesn't match the expected value"
+    );
+    assert_eq!(CasError::MissingKey(0).to_string(), "no value is associated with the key");
+    let insert_error = InsertError::from(duplicate_error.clone());
+    assert_eq!(insert_error, InsertError::DuplicateKey(duplicate_error));
+    assert_eq!(insert_error.to_string(), "duplicate key at index 2, first occurrence at index 1");
+    let reserve_error = AssocList::<u8, u8>::new().try_reserve(usize::MAX).expect_err("overflow");
+    assert_eq!(
+        InsertError::<u8>::from(reserve_error.clone()).to_string(),
+        reserve_error.to_string()
+    );
+}
+
+#[test]
+fn try_insert() {
+    let mut assoc_list = assoc_list!(("key", 1));
+
+    let inserted = assoc_list.try_insert("new", 2).expect("key is not contained");
+    *inserted = 3;
+    let occupied_error = assoc_list.try_insert("key", 4).expect_err("key is contained");
+    assert_eq!(occupied_error.to_string(), "key already exists at index 0");
+    let (entry, value) = occupied_error.into_parts();
+    assert_eq!((entry.key(), entry.index(), value), (&"key", 0, 4));
+    assert_eq!(assoc_list.vec, Vec::from([("key", 1), ("new", 3)]), "existing value is unchanged");
+}
+
+#[test]
+fn get_index_of() {
+    let mut assoc_list = assoc_list!(("a", 1), ("b", 2), ("c", 3), ("d", 4));
+    assert_eq!(assoc_list.get_index_of("a"), Some(0));
+    assert_eq!(assoc_list.get_index_of("d"), Some(3));
+    assert_eq!(assoc_list.get_index_of("missing"), None);
+    let index = assoc_list.get_index_of("c").expect("Key is contained!");
+    assert_eq!(assoc_list.get_index(index), Some((&"c", &3)), "consistent with get_index");
+    assert_eq!(assoc_list.get_index(4), None, "out of bounds");
+
+    assert_eq!(assoc_list.swap_remove("a"), Some(1));
+    assert_eq!(assoc_list.get_index_of("d"), Some(0), "swap_remove moves the last element");
+    assert_eq!(assoc_list.get_index_of("b"), Some(1));
+    assert_eq!(assoc_list.get_index(0), Some((&"d", &4)));
+}
+
+#[test]
+fn get_full() {
+    let mut assoc_list = assoc_list!(("first", 1), ("middle", 2), ("last", 3));
+    assert_eq!(assoc_list.get_full("first"), Some((0, &"first", &1)));
+    assert_eq!(assoc_list.get_full("last"), Some((2, &"last", &3)));
+    assert_eq!(assoc_list.get_full("missing"), None);
+
+    if let Some((index, key, value)) = assoc_list.get_full_mut("first") {
+        assert_eq!((index, key), (0, &"first"));
+        *value = 10;
+    }
+    if let Some((index, key, value)) = assoc_list.get_full_mut("last") {
+        assert_eq!((index, key), (2, &"last"));
+        *value = 30;
+    }
+    assert!(assoc_list.get_full_mut("missing").is_none());
+    assert_eq!(assoc_list.vec, Vec::from([("first", 10), ("middle", 2), ("last", 30)]));
+}
+
+#[test]
+fn insert_full() {
+    let mut assoc_list = AssocList::new();
+    assert_eq!(assoc_list.insert_full("a", 1), (0, None));
+    assert_eq!(assoc_list.insert_full("b", 2), (1, None));
+    assert_eq!(assoc_list.insert_full("c", 3), (2, None));
+    assert_eq!(assoc_list.insert_full("b", 20), (1, Some(2)), "replace keeps the position");
+
+    assert_eq!(assoc_list.swap_remove("a"), Some(1));
+    assert_eq!(assoc_list.insert_full("c", 30), (0, Some(3)), "position after swap-remove");
+    assert_eq!(assoc_list.insert_full("d", 4), (2, None), "append after swap-remove");
+    assert_eq!(assoc_list.vec, Vec::from([("c", 30), ("b", 20), ("d", 4)]));
+}
+
+#[test]
+fn shift_insert() {
+    let mut assoc_list = assoc_list!(("b", 2), ("c", 3));
+    assert_eq!(assoc_list.shift_insert(0, "a", 1), None, "insert at the front");
+    assert_eq!(assoc_list.shift_insert(3, "d", 4), None, "insert at len");
+    assert_eq!(assoc_list.vec, Vec::from([("a", 1), ("b", 2), ("c", 3), ("d", 4)]));
+
+    assert_eq!(assoc_list.shift_insert(3, "a", 10), Some(1), "move an existing key backward");
+    assert_eq!(assoc_list.vec, Vec::from([("b", 2), ("c", 3), ("d", 4), ("a", 10)]));
+    assert_eq!(assoc_list.shift_insert(1, "d", 40), Some(4), "move an existing key forward");
+    assert_eq!(assoc_list.vec, Vec::from([("b", 2), ("d", 40), ("c", 3), ("a", 10)]));
+    assert_eq!(assoc_list.shift_insert(2, "c", 30), Some(3), "replace in place");
+    assert_eq!(assoc_list.vec, Vec::from([("b", 2), ("d", 40), ("c", 30), ("a", 10)]));
+}
+
+#[test]
+#[should_panic = "index"]
+fn shift_insert_out_of_bounds() {
+    let mut assoc_list = assoc_list!(("a", 1));
+    let _ = assoc_list.shift_insert(2, "b", 2);
+}
+
+#[test]
+#[should_panic = "shift_insert index (is 1) should be < len (is 1)"]
+fn shift_insert_existing_out_of_bounds() {
+    let mut assoc_list = assoc_list!(("a", 1));
+    let _ = assoc_list.shift_insert(1, "a", 2);
+}
+
+#[test]
+fn insert_before_after() {
+    let mut headers = assoc_list!(("content-type", "text/html"), ("server", "test"));
+    assert_eq!(headers.insert_after("content-type", "charset", "utf-8"), Ok(None));
+    assert_eq!(headers.insert_before("content-type", "date", "today"), Ok(None));
+    assert_eq!(headers.insert_after("server", "length", "42"), Ok(None), "after the last element");
+    assert_eq!(
+        headers.vec,
+        Vec::from([
+            ("date", "today"),
+            ("content-type", "text/html"),
+            ("charset", "utf-8"),
+            ("server", "test"),
+            ("length", "42"),
+        ])
+    );
+
+    assert_eq!(headers.insert_after("missing", "key", "value"), Err(("key", "value")));
+    assert_eq!(headers.insert_before("missing", "key", "value"), Err(("key", "value")));
+    assert_eq!(headers.len(), 5, "nothing inserted for a missing anchor");
+
+    assert_eq!(headers.insert_after("server", "server", "replaced"), Ok(Some("test")));
+    assert_eq!(headers.get_index(3), Some((&"server", &"replaced")), "key equals anchor");
+
+    assert_eq!(headers.insert_after("server", "date", "yesterday"), Ok(Some("today")));
+    assert_eq!(headers.insert_before("content-type", "length", "7"), Ok(Some("42")));
+    assert_eq!(
+        headers.vec,
+        Vec::from([
+            ("length", "7"),
+            ("content-type", "text/html"),
+            ("charset", "utf-8"),
+            ("server", "replaced"),
+            ("date", "yesterday"),
+        ]),
+        "existing keys are moved next to the anchor"
+    );
+    assert_eq!(headers.insert_before("server", "length", "8"), Ok(Some("7")));
+    assert_eq!(headers.insert_after("charset", "date", "tomorrow"), Ok(Some("yesterday")));
+    assert_eq!(
+        headers.vec,
+        Vec::from([
+            ("content-type", "text/html"),
+            ("charset", "utf-8"),
+            ("date", "tomorrow"),
+            ("length", "8"),
+            ("server", "replaced"),
+        ])
+    );
+}
+
+#[test]
+fn move_index() {
+    let mut layers = assoc_list!(("a", 1), ("b", 2), ("c", 3), ("d", 4));
+    layers.move_index(0, 2);
+    assert_eq!(layers.vec, Vec::from([("b", 2), ("c", 3), ("a", 1), ("d", 4)]), "move backward");
+    layers.move_index(3, 0);
+    assert_eq!(layers.vec, Vec::from([("d", 4), ("b", 2), ("c", 3), ("a", 1)]), "move forward");
+    layers.move_index(1, 1);
+    assert_eq!(layers.vec, Vec::from([("d", 4), ("b", 2), ("c", 3), ("a", 1)]), "no-op");
+}
+
+#[test]
+#[should_panic = "move_index to (is 2) should be < len (is 2)"]
+fn move_index_out_of_bounds() {
+    let mut layers = assoc_list!(("a", 1), ("b", 2));
+    layers.move_index(0, 2);
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn swap_indices(reference_map: BTreeMap<u8, u16>, first: usize, second: usize) {
+    let mut assoc_list: AssocList<_, _> = reference_map.clone().into_iter().collect();
+    let (Some(first_index), Some(second_index)) =
+        (first.checked_rem(assoc_list.len()), second.checked_rem(assoc_list.len()))
+    else {
+        // nothing to swap in an empty AssocList
+        return;
+    };
+    let before = assoc_list.vec.clone();
+    assoc_list.swap_indices(first_index, second_index);
+    assert_eq!(assoc_list.vec.get(first_index), before.get(second_index));
+    assert_eq!(assoc_list.vec.get(second_index), before.get(first_index));
+    for (index, pair) in assoc_list.vec.iter().enumerate() {
+        if index != first_index && index != second_index {
+            assert_eq!(Some(pair), before.get(index), "other elements are unchanged");
+        }
+    }
+    let swapped: BTreeMap<_, _> = assoc_list.into_iter().collect();
+    assert_eq!(swapped, reference_map, "the key-value pairs are unchanged");
+}
+
+#[test]
+fn get_range() {
+    let assoc_list = assoc_list!(("a", 1), ("b", 2), ("c", 3), ("d", 4));
+    assert_eq!(assoc_list.get_range(1..3), Some([("b", 2), ("c", 3)].as_slice()));
+    assert_eq!(assoc_list.get_range(..), Some(assoc_list.vec.as_slice()));
+    assert_eq!(assoc_list.get_range(2..), Some([("c", 3), ("d", 4)].as_slice()));
+    assert_eq!(assoc_list.get_range(..=0), Some([("a", 1)].as_slice()));
+    assert_eq!(assoc_list.get_range(4..), Some([].as_slice()), "empty range at the end");
+    assert_eq!(assoc_list.get_range(2..5), None, "end out of bounds");
+    assert_eq!(assoc_list.get_range(5..), None, "start out of bounds");
+    assert_eq!(assoc_list.get_range(..=usize::MAX), None, "inclusive end overflows");
+    let start = 3;
+    assert_eq!(assoc_list.get_range(start..1), None, "inverted range");
+}
+
+#[test]
+fn first_last() {
+    let mut empty = AssocList::<u8, char>::new();
+    assert_eq!(empty.first(), None);
+    assert_eq!(empty.last(), None);
+    assert_eq!(empty.first_mut(), None);
+    assert_eq!(empty.last_mut(), None);
+
+    let mut events = assoc_list!((1, 'a'), (2, 'b'), (3, 'c'));
+    assert_eq!(events.first(), Some((&1, &'a')));
+    assert_eq!(events.last(), Some((&3, &'c')));
+    if let Some((key, value)) = events.first_mut() {
+        assert_eq!(key, &1);
+        *value = 'x';
+    }
+    if let Some((key, value)) = events.last_mut() {
+        assert_eq!(key, &3);
+        *value = 'z';
+    }
+    assert_eq!(events.vec, Vec::from([(1, 'x'), (2, 'b'), (3, 'z')]));
+}
+
+#[test]
+fn pop() {
+    let mut scopes = assoc_list!(("outer", 1), ("middle", 2), ("inner", 3));
+    assert_eq!(scopes.pop(), Some(("inner", 3)), "most recently inserted");
+    let _ = scopes.insert("new", 4);
+    assert_eq!(scopes.pop(), Some(("new", 4)));
+    let _ = scopes.insert("another", 5);
+    assert_eq!(scopes.swap_remove("outer"), Some(1));
+    assert_eq!(scopes.pop(), Some(("middle", 2)), "swap_remove moved the last element");
+    assert_eq!(scopes.pop(), Some(("another", 5)));
+    assert_eq!(scopes.pop(), None, "empty AssocList");
+}
+
+#[test]
+fn pop_if() {
+    let mut scopes = assoc_list!(("global", 0), ("function", 1), ("block", 2));
+    assert_eq!(scopes.pop_if(|_key, depth| *depth > 2), None, "predicate is false");
+    assert_eq!(scopes.len(), 3);
+    assert_eq!(
+        scopes.pop_if(|_key, depth| {
+            *depth += 10;
+            *depth > 1
+        }),
+        Some(("block", 12)),
+        "the value may be modified before removal"
+    );
+    while scopes.pop_if(|_key, depth| *depth > 0).is_some() {}
+    assert_eq!(scopes.vec, Vec::from([("global", 0)]));
+    scopes.clear();
+    assert_eq!(scopes.pop_if(|_key, _depth| true), None, "empty AssocList");
+}
+
+#[test]
+fn reverse() {
+    let mut attributes = assoc_list!(("newest", 3), ("middle", 2), ("oldest", 1));
+    attributes.reverse();
+    assert_eq!(attributes.vec, Vec::from([("oldest", 1), ("middle", 2), ("newest", 3)]));
+    assert_eq!(attributes.get("middle"), Some(&2));
+
+    let mut empty = AssocList::<u8, u8>::new();
+    empty.reverse();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn rotate() {
+    let mut workers = assoc_list!((1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'));
+    workers.rotate_left(1);
+    assert_eq!(workers.keys().copied().collect::<Vec<_>>(), [2, 3, 4, 1], "front to back");
+    workers.rotate_right(1);
+    assert_eq!(workers.keys().copied().collect::<Vec<_>>(), [1, 2, 3, 4], "back to front");
+    workers.rotate_left(3);
+    assert_eq!(workers.keys().copied().collect::<Vec<_>>(), [4, 1, 2, 3]);
+    workers.rotate_right(3);
+    assert_eq!(workers.keys().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+    workers.rotate_left(0);
+    workers.rotate_right(0);
+    assert_eq!(workers.keys().copied().collect::<Vec<_>>(), [1, 2, 3, 4], "mid == 0");
+    workers.rotate_left(4);
+    workers.rotate_right(4);
+    assert_eq!(workers.keys().copied().collect::<Vec<_>>(), [1, 2, 3, 4], "mid == len");
+}
+
+#[test]
+#[should_panic = "mid <= self.len()"]
+fn rotate_out_of_bounds() {
+    let mut workers = assoc_list!((1, 'a'), (2, 'b'));
+    workers.rotate_left(3);
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn retain(input: Vec<(u8, u16)>) {
+    let mut assoc_list: AssocList<_, _> = input.iter().copied().collect();
+    let mut reference_map: BTreeMap<_, _> = input.into_iter().collect();
+    let mut expected_vec = assoc_list.vec.clone();
+    let keep = |key: &u8, value: &mut u16| {
+        *value = value.wrapping_add(1);
+        key % 3 != 0
+    };
+    assoc_list.retain(keep);
+    reference_map.retain(keep);
+    expected_vec.retain_mut(|(key, value)| keep(key, value));
+    assert_eq!(assoc_list.vec, expected_vec, "the order of the remaining elements is preserved");
+    let retained_map: BTreeMap<_, _> = assoc_list.into_iter().collect();
+    assert_eq!(retained_map, reference_map);
+}
+
+#[test]
+fn split_off() {
+    let mut headers = assoc_list!(("a", 1), ("b", 2), ("x-c", 3), ("x-d", 4));
+    let extensions = headers.split_off(2);
+    assert_eq!(headers.vec, Vec::from([("a", 1), ("b", 2)]));
+    assert_eq!(extensions.vec, Vec::from([("x-c", 3), ("x-d", 4)]));
+
+    let empty = headers.split_off(2);
+    assert!(empty.is_empty(), "split at len");
+    assert_eq!(headers.len(), 2);
+    let all = headers.split_off(0);
+    assert!(headers.is_empty(), "split at 0");
+    assert_eq!(all.vec, Vec::from([("a", 1), ("b", 2)]));
+}
+
+#[test]
+#[should_panic = "`at` split index (is 3) should be <= len (is 2)"]
+fn split_off_out_of_bounds() {
+    let mut assoc_list = assoc_list!(("a", 1), ("b", 2));
+    let _ = assoc_list.split_off(3);
+}
+
+#[test]
+fn split_off_by_key() {
+    let mut routes = assoc_list!((30, 'c'), (10, 'a'), (50, 'e'), (20, 'b'), (40, 'd'));
+    let upper = routes.split_off_by_key(&30);
+    assert_eq!(routes.vec, Vec::from([(10, 'a'), (20, 'b')]), "partition of unsorted input");
+    assert_eq!(upper.vec, Vec::from([(30, 'c'), (50, 'e'), (40, 'd')]), "relative order is kept");
+
+    let mut floats = assoc_list!((f32::NAN, 0), (1.5, 1), (-2.0, 2));
+    let upper_floats = floats.split_off_by_key(&0.0);
+    assert_eq!(upper_floats.vec, Vec::from([(1.5, 1)]));
+    assert_eq!(floats.len(), 2, "incomparable keys stay");
+}
+
+#[test]
+fn partition() {
+    let settings =
+        assoc_list!(("theme", true), ("window", false), ("font", true), ("cursor", false));
+    let (persistent, transient) = settings.partition(|_key, persist| *persist);
+    assert_eq!(persistent.vec, Vec::from([("theme", true), ("font", true)]));
+    assert_eq!(transient.vec, Vec::from([("window", false), ("cursor", false)]));
+
+    let (all, none) = persistent.partition(|_key, _persist| true);
+    assert_eq!(all.len(), 2);
+    assert!(none.is_empty());
+}
+
+#[test]
+fn remove_many() {
+    let mut subscribers = assoc_list!((1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e'));
+    assert_eq!(subscribers.remove_many(&[4, 2, 7, 2]), 2, "missing and repeated keys");
+    assert_eq!(subscribers.vec, Vec::from([(1, 'a'), (3, 'c'), (5, 'e')]), "order is preserved");
+    assert_eq!(subscribers.remove_many::<i32, _>(&[]), 0);
+    assert_eq!(subscribers.len(), 3);
+
+    let mut strings = assoc_list!((String::from("a"), 1), (String::from("b"), 2));
+    assert_eq!(strings.remove_many(["b"]), 1, "borrowed keys");
+    assert_eq!(strings.remove_many(Vec::from(["a"]).into_iter()), 1);
+    assert!(strings.is_empty());
+}
+
+#[test]
+fn replace_key() {
+    let mut settings = assoc_list!(("colour", 1), ("font", 2), ("size", 3));
+    assert_eq!(settings.replace_key("colour", "color"), Ok("colour"));
+    assert_eq!(settings.vec, Vec::from([("color", 1), ("font", 2), ("size", 3)]), "same position");
+
+    assert_eq!(settings.replace_key("missing", "new"), Err("new"), "missing old key");
+    assert_eq!(settings.replace_key("font", "size"), Err("size"), "duplicate target");
+    assert_eq!(settings.vec, Vec::from([("color", 1), ("font", 2), ("size", 3)]), "untouched");
+    assert_eq!(settings.replace_key("font", "font"), Ok("font"), "new equal to old");
+}
+
+#[test]
+fn update() {
+    let mut counters = assoc_list!((String::from("hits"), 1));
+    assert!(counters.update("hits", |count| *count += 1));
+    assert!(!counters.update("misses", |count| *count += 1));
+    assert_eq!(counters.get("hits"), Some(&2));
+    assert_eq!(counters.len(), 1, "missing keys are not inserted");
+
+    assert_eq!(*counters.update_or_insert(String::from("hits"), 0, |count| *count += 1), 3);
+    assert_eq!(*counters.update_or_insert(String::from("misses"), 0, |count| *count += 1), 0);
+    assert_eq!(counters.get("misses"), Some(&0), "default is inserted without update");
+}
+
+#[test]
+fn compute() {
+    let increment = |_key: &&str, count: Option<u8>| Some(count.unwrap_or(0) + 1);
+    let decrement = |_key: &&str, count: Option<u8>| {
+        count.and_then(|current| current.checked_sub(1)).filter(|remaining| *remaining > 0)
+    };
+
+    let mut registrations = AssocList::new();
+    assert_eq!(registrations.compute("a", decrement), None, "vacant and None");
+    assert_eq!(registrations.capacity(), 0, "vacant and None doesn't allocate");
+    assert_eq!(registrations.compute("a", increment), Some(&mut 1), "vacant and Some");
+    assert_eq!(registrations.compute("b", increment), Some(&mut 1));
+    assert_eq!(registrations.compute("c", increment), Some(&mut 1));
+    assert_eq!(registrations.compute("a", increment), Some(&mut 2), "occupied and Some");
+    assert_eq!(registrations.vec, Vec::from([("a", 2), ("b", 1), ("c", 1)]), "position is kept");
+
+    assert_eq!(registrations.compute("a", decrement), Some(&mut 1));
+    assert_eq!(registrations.compute("a", decrement), None, "occupied and None");
+    assert_eq!(registrations.vec, Vec::from([("b", 1), ("c", 1)]), "order is preserved");
+
+    assert_eq!(registrations.compute("d", increment), Some(&mut 1));
+    assert_eq!(registrations.compute("e", increment), Some(&mut 1));
+    assert_eq!(registrations.compute("c", increment), Some(&mut 2), "middle and Some");
+    assert_eq!(registrations.vec, Vec::from([("b", 1), ("c", 2), ("d", 1), ("e", 1)]));
+    assert_eq!(registrations.compute("b", decrement), None, "first and None");
+    assert_eq!(registrations.compute("e", decrement), None, "last and None");
+    assert_eq!(registrations.vec, Vec::from([("c", 2), ("d", 1)]), "order is preserved");
+}
+
+#[test]
+fn swap_values() {
+    let mut bindings = assoc_list!(("x", 1), ("y", 2), ("z", 3));
+    assert!(bindings.swap_values("x", "z"));
+    assert_eq!(bindings.vec, Vec::from([("x", 3), ("y", 2), ("z", 1)]));
+    assert!(bindings.swap_values("y", "x"), "order of the arguments doesn't matter");
+    assert_eq!(bindings.vec, Vec::from([("x", 2), ("y", 3), ("z", 1)]));
+
+    assert!(!bindings.swap_values("x", "x"), "same key");
+    assert!(!bindings.swap_values("x", "missing"), "missing key");
+    assert!(!bindings.swap_values("missing", "x"), "missing key");
+    assert_eq!(bindings.vec, Vec::from([("x", 2), ("y", 3), ("z", 1)]), "untouched");
+}
+
+#[test]
+fn move_to_front_back() {
+    const CAPACITY: usize = 3;
+    let mut cache = AssocList::with_capacity(CAPACITY);
+    let access = |lru: &mut AssocList<u8, char>, key: u8, value: char| {
+        if !lru.move_to_back(&key) {
+            if lru.len() == CAPACITY {
+                // evict the least recently used element
+                let _ = lru.entry_at(0).map(IndexedEntry::remove);
+            }
+            let _ = lru.insert(key, value);
+        }
+    };
+    access(&mut cache, 1, 'a');
+    access(&mut cache, 2, 'b');
+    access(&mut cache, 3, 'c');
+    access(&mut cache, 1, 'a');
+    assert_eq!(cache.vec, Vec::from([(2, 'b'), (3, 'c'), (1, 'a')]), "hit moves to the back");
+    access(&mut cache, 4, 'd');
+    assert_eq!(cache.vec, Vec::from([(3, 'c'), (1, 'a'), (4, 'd')]), "least recently used evicted");
+    access(&mut cache, 4, 'd');
+    assert_eq!(cache.vec, Vec::from([(3, 'c'), (1, 'a'), (4, 'd')]), "hit on the last element");
+
+    assert!(cache.move_to_front(&4));
+    assert_eq!(cache.vec, Vec::from([(4, 'd'), (3, 'c'), (1, 'a')]));
+    assert!(cache.move_to_front(&4), "already at the front");
+    assert_eq!(cache.vec, Vec::from([(4, 'd'), (3, 'c'), (1, 'a')]));
+    assert!(!cache.move_to_front(&7));
+    assert!(!cache.move_to_back(&7));
+}
+
+#[test]
+fn retain_map() {
+    let mut parsed = AssocList::with_capacity(8);
+    parsed.extend([("a", " 1"), ("b", "x"), ("c", "3 "), ("d", "")]);
+    let capacity = parsed.capacity();
+    parsed.retain_map(|_key, value| (!value.trim().is_empty()).then(|| value.trim()));
+    assert_eq!(parsed.vec, Vec::from([("a", "1"), ("b", "x"), ("c", "3")]));
+    assert_eq!(parsed.capacity(), capacity, "capacity is preserved");
+
+    let numbers = parsed.filter_map_values(|_key, value| value.parse::<u8>().ok());
+    assert_eq!(numbers.vec, Vec::from([("a", 1), ("c", 3)]), "order is preserved");
+}
+
+#[test]
+fn assign() {
+    let mut frame = AssocList::with_capacity(8);
+    frame.assign([(1, 'a'), (2, 'b'), (1, 'c')]);
+    assert_eq!(frame.vec, Vec::from([(1, 'c'), (2, 'b')]), "later values are kept");
+    let capacity = frame.capacity();
+
+    frame.assign([(3, 'd'), (4, 'e'), (5, 'f')]);
+    assert_eq!(frame.vec, Vec::from([(3, 'd'), (4, 'e'), (5, 'f')]));
+    assert_eq!(frame.capacity(), capacity, "allocation is reused");
+
+    frame.assign([]);
+    assert!(frame.is_empty());
+    assert_eq!(frame.capacity(), capacity, "capacity is never reduced");
+}
+
+#[test]
+fn extract_keys() {
+    let mut parameters = assoc_list!(("user", 1), ("page", 2), ("sort", 3), ("limit", 4));
+    let handled = parameters.extract_keys(["limit", "missing", "user"]);
+    assert_eq!(handled.vec, Vec::from([("user", 1), ("limit", 4)]), "order of the AssocList");
+    assert_eq!(parameters.vec, Vec::from([("page", 2), ("sort", 3)]), "order is preserved");
+
+    let none = parameters.extract_keys::<str, _>([]);
+    assert!(none.is_empty());
+    assert_eq!(parameters.len(), 2);
+}
+
+#[test]
+fn retain_remove_keys_in() {
+    let permissions = assoc_list!(("read", ()), ("exec", ()));
+    let mut restricted = assoc_list!(("write", 1), ("read", 2), ("exec", 3), ("admin", 4));
+    let mut without = restricted.clone();
+    let mut by_keys = restricted.clone();
+
+    restricted.retain_keys_in(&permissions);
+    assert_eq!(restricted.vec, Vec::from([("read", 2), ("exec", 3)]));
+    without.remove_keys_in(&permissions);
+    assert_eq!(without.vec, Vec::from([("write", 1), ("admin", 4)]));
+    by_keys.retain_keys(["exec", "missing", "write"]);
+    assert_eq!(by_keys.vec, Vec::from([("write", 1), ("exec", 3)]), "order is preserved");
+
+    without.remove_keys_in(&AssocList::<&str, ()>::new());
+    assert_eq!(without.len(), 2, "empty other removes nothing");
+    without.retain_keys_in(&AssocList::<&str, ()>::new());
+    assert!(without.is_empty(), "empty other retains nothing");
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn union(first: Vec<(u8, u16)>, second: Vec<(u8, u16)>) {
+    let first_list: AssocList<_, _> = first.iter().copied().collect();
+    let second_list: AssocList<_, _> = second.iter().copied().collect();
+    let union: Vec<_> = first_list.union(&second_list).map(|(key, value)| (*key, *value)).collect();
+    assert_eq!(union.get(..first_list.len()), Some(first_list.vec.as_slice()), "self comes first");
+
+    let mut reference_map: BTreeMap<_, _> = second.into_iter().collect();
+    reference_map.extend(first);
+    let mut sorted_union = union;
+    sorted_union.sort_unstable();
+    assert_eq!(sorted_union, reference_map.into_iter().collect::<Vec<_>>(), "values of self win");
+}
+
+#[test]
+fn intersection() {
+    let expected = assoc_list!(("timeout", 30), ("retries", 3), ("verbose", 0));
+    let actual = assoc_list!(("verbose", "yes"), ("timeout", "30"), ("color", "auto"));
+    let common: Vec<_> = expected.intersection(&actual).collect();
+    assert_eq!(
+        common,
+        Vec::from([(&"timeout", &30, &"30"), (&"verbose", &0, &"yes")]),
+        "order of self, values of both"
+    );
+
+    let empty = AssocList::<&str, ()>::new();
+    assert_eq!(expected.intersection(&empty).count(), 0);
+    assert_eq!(empty.intersection(&actual).count(), 0);
+
+    let overlapping: Vec<_> =
+        expected.intersection(&expected).map(|(key, _value, _other)| *key).collect();
+    assert_eq!(overlapping, Vec::from(["timeout", "retries", "verbose"]));
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn difference(first: Vec<(u8, u16)>, second: Vec<(u8, char)>) {
+    let first_list: AssocList<_, _> = first.iter().copied().collect();
+    let second_list: AssocList<_, _> = second.iter().copied().collect();
+    let difference: Vec<_> =
+        first_list.difference(&second_list).map(|(key, value)| (*key, *value)).collect();
+
+    let removed_keys: BTreeSet<_> = second.into_iter().map(|(key, _value)| key).collect();
+    let expected: Vec<_> =
+        first_list.vec.into_iter().filter(|(key, _value)| !removed_keys.contains(key)).collect();
+    assert_eq!(difference, expected, "insertion order is preserved");
+    let mut reference_map: BTreeMap<_, _> = first.into_iter().collect();
+    reference_map.retain(|key, _value| !removed_keys.contains(key));
+    let mut sorted_difference = difference;
+    sorted_difference.sort_unstable();
+    assert_eq!(sorted_difference, reference_map.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn symmetric_difference() {
+    let left = assoc_list!(("a", 1), ("shared", 2), ("b", 3));
+    let right = assoc_list!(("c", 4), ("shared", 5), ("d", 6));
+    let unique: Vec<_> = left.symmetric_difference(&right).collect();
+    assert_eq!(
+        unique,
+        Vec::from([(&"a", &1), (&"b", &3), (&"c", &4), (&"d", &6)]),
+        "entries of self first, then entries of other, each in insertion order"
+    );
+    assert_eq!(left.symmetric_difference(&left).count(), 0);
+    assert_eq!(left.symmetric_difference(&AssocList::new()).count(), 3);
+}
+
+#[test]
+fn is_submap_superset() {
+    let required = assoc_list!(("port", 80), ("host", 1));
+    let effective = assoc_list!(("host", 1), ("debug", 0), ("port", 80));
+    let changed = assoc_list!(("host", 1), ("port", 8080));
+
+    assert!(required.is_submap(&effective), "order is irrelevant");
+    assert!(effective.is_superset(&required));
+    assert!(!effective.is_submap(&required), "debug is missing");
+    assert!(!required.is_submap(&changed), "port has a different value");
+    assert!(required.keys_subset_of(&changed), "values are ignored");
+    assert!(changed.keys_superset_of(&required));
+    assert!(!effective.keys_subset_of(&changed));
+    assert!(AssocList::<&str, i32>::new().is_submap(&required), "empty AssocList");
+
+    let nan = assoc_list!(("value", f32::NAN));
+    assert!(!nan.is_submap(&nan), "NaN is not equal to itself");
+    assert!(nan.keys_subset_of(&nan));
+}
+
+#[test]
+fn keys_disjoint() {
+    let built_in = assoc_list!(("ctrl+c", "copy"), ("ctrl+v", "paste"), ("ctrl+s", "save"));
+    let user = assoc_list!(("ctrl+k", 1));
+    let colliding = assoc_list!(("ctrl+k", 'k'), ("ctrl+s", 's'));
+
+    assert!(built_in.keys_disjoint(&user));
+    assert!(user.keys_disjoint(&built_in), "the smaller list can be either one");
+    assert!(!built_in.keys_disjoint(&colliding));
+    assert!(!colliding.keys_disjoint(&built_in));
+    assert!(built_in.keys_disjoint(&AssocList::<&str, ()>::new()), "empty AssocList");
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn merge_shards(shards: Vec<Vec<(u8, u16)>>) -> bool {
+    let lists: Vec<AssocList<_, _>> =
+        shards.iter().map(|shard| shard.iter().copied().collect()).collect();
+    let expected: AssocList<_, _> = shards.into_iter().flatten().collect();
+    let collected: AssocList<_, _> = lists.clone().into_iter().collect();
+    let summed: AssocList<_, _> = lists.into_iter().sum();
+    collected.vec == expected.vec && summed.vec == expected.vec
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn join(first: Vec<(u8, u16)>, second: Vec<(u8, u32)>) {
+    let first_list: AssocList<_, _> = first.into_iter().collect();
+    let second_list: AssocList<_, _> = second.into_iter().collect();
+
+    let expected: Vec<_> = first_list
+        .intersection(&second_list)
+        .map(|(key, value, other_value)| (*key, u32::from(*value) ^ *other_value))
+        .collect();
+    let joined: Vec<_> = first_list
+        .join(&second_list)
+        .map(|(key, value, other_value)| (*key, u32::from(*value) ^ *other_value))
+        .collect();
+    assert_eq!(joined, expected);
+    let join_mapped =
+        first_list.join_map(second_list, |_key, value, other_value| u32::from(value) ^ other_value);
+    assert_eq!(join_mapped.vec, expected);
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn set_operators(first: Vec<(u8, u16)>, second: Vec<(u8, u16)>) {
+    let first_list: AssocList<_, _> = first.into_iter().collect();
+    let second_list: AssocList<_, _> = second.into_iter().collect();
+    let copy_pair = |(key, value): (&u8, &u16)| (*key, *value);
+
+    let union = &first_list | &second_list;
+    assert_eq!(union.vec, first_list.union(&second_list).map(copy_pair).collect::<Vec<_>>());
+    let intersection = &first_list & &second_list;
+    let expected_intersection: Vec<_> =
+        first_list.intersection(&second_list).map(|(key, value, _other)| (*key, *value)).collect();
+    assert_eq!(intersection.vec, expected_intersection);
+    // set difference, not an arithmetic operation
+    #[allow(clippy::arithmetic_side_effects)]
+    let difference = &first_list - &second_list;
+    assert_eq!(
+        difference.vec,
+        first_list.difference(&second_list).map(copy_pair).collect::<Vec<_>>()
+    );
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn pop_first_last(reference_map: BTreeMap<i16, u8>, input_order: Vec<i16>) {
+    // insert in an arbitrary order, so the key order differs from the insertion order
+    let mut assoc_list = AssocList::new();
+    for key in input_order.iter().chain(reference_map.keys()) {
+        if let Some(value) = reference_map.get(key) {
+            let _ = assoc_list.insert(*key, *value);
+        }
+    }
+    let mut from_back = assoc_list.clone();
+    let mut reference_front = reference_map.clone();
+    let mut reference_back = reference_map;
+    while let Some(pair) = assoc_list.pop_first() {
+        assert_eq!(Some(pair), reference_front.pop_first());
+    }
+    assert!(reference_front.is_empty());
+    while let Some(pair) = from_back.pop_last() {
+        assert_eq!(Some(pair), reference_back.pop_last());
+    }
+    assert!(reference_back.is_empty());
+}
+
+#[test]
+fn pop_first_last_order() {
+    let mut priorities = assoc_list!((3, 'c'), (1, 'a'), (4, 'd'), (2, 'b'));
+    assert_eq!(priorities.pop_first(), Some((1, 'a')));
+    assert_eq!(priorities.pop_last(), Some((4, 'd')));
+    assert_eq!(priorities.vec, Vec::from([(3, 'c'), (2, 'b')]), "order is preserved");
+    assert_eq!(AssocList::<u8, u8>::new().pop_first(), None);
+    assert_eq!(AssocList::<u8, u8>::new().pop_last(), None);
+}
+
+#[test]
+fn sort_by() {
+    let mut floats = assoc_list!((2.5f32, 'b'), (f32::NAN, 'n'), (-1.0, 'a'), (-0.0, 'z'));
+    floats.sort_by(|key, _value, other_key, _other_value| key.total_cmp(other_key));
+    assert_eq!(floats.values().collect::<String>(), "azbn");
+
+    let mut tasks = assoc_list!(("write", 2), ("read", 1), ("test", 2), ("plan", 0));
+    tasks.sort_by_key(|_key, priority| *priority);
+    assert_eq!(
+        tasks.vec,
+        Vec::from([("plan", 0), ("read", 1), ("write", 2), ("test", 2)]),
+        "sort is stable"
+    );
+    tasks.sort_by(|key, _value, other_key, _other_value| other_key.cmp(key));
+    assert_eq!(tasks.keys().copied().collect::<Vec<_>>(), ["write", "test", "read", "plan"]);
+}
+
+#[test]
+fn sort_by_values() {
+    let mut frequencies = assoc_list!(("the", 5), ("a", 3), ("of", 5), ("and", 1), ("to", 3));
+    let mut unstable = frequencies.clone();
+    frequencies.sort_by_values();
+    assert_eq!(
+        frequencies.vec,
+        Vec::from([("and", 1), ("a", 3), ("to", 3), ("the", 5), ("of", 5)]),
+        "equal values keep their insertion order"
+    );
+    unstable.sort_unstable_by_values();
+    assert_eq!(unstable.values().copied().collect::<Vec<_>>(), [1, 3, 3, 5, 5]);
+    assert_eq!(unstable.get("of"), Some(&5), "key-value pairs stay intact");
+}
+
+#[test]
+fn sorted_by() {
+    let assoc_list = assoc_list!(("b", 2), ("c", 1), ("a", 2));
+    let by_value: Vec<_> = assoc_list
+        .clone()
+        .sorted_by(|_key, value, _other_key, other_value| value.cmp(other_value))
+        .collect();
+    assert_eq!(by_value, [("c", 1), ("b", 2), ("a", 2)], "sort is stable");
+    let by_key: Vec<_> = assoc_list
+        .sorted_unstable_by(|key, _value, other_key, _other_value| key.cmp(other_key))
+        .collect();
+    assert_eq!(by_key, [("a", 2), ("b", 2), ("c", 1)]);
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn insert_sorted(input: Vec<(i16, u8)>) {
+    let mut assoc_list = AssocList::new();
+    let mut reference_map = BTreeMap::new();
+    for (key, value) in input {
+        let (index, previous) = assoc_list.insert_sorted(key, value);
+        assert_eq!(previous, reference_map.insert(key, value));
+        assert_eq!(assoc_list.get_index(index), Some((&key, &value)));
+    }
+    let expected: Vec<_> = reference_map.into_iter().collect();
+    assert_eq!(assoc_list.vec, expected, "same order as the BTreeMap");
+}
+
+#[test]
+fn binary_search() {
+    let mut timestamps = assoc_list!((10, 'a'), (20, 'b'), (30, 'c'), (40, 'd'));
+    assert_eq!(timestamps.binary_search_keys(&30), Ok(2));
+    assert_eq!(timestamps.binary_search_keys(&5), Err(0));
+    assert_eq!(timestamps.binary_search_keys(&35), Err(3));
+    assert_eq!(timestamps.binary_search_by(|_key, value| value.cmp(&'b')), Ok(1));
+    assert_eq!(timestamps.binary_search_by(|_key, value| value.cmp(&'z')), Err(4));
+
+    let now = 25;
+    let expired = timestamps.partition_point(|timestamp, _value| *timestamp < now);
+    assert_eq!(expired, 2);
+    assert_eq!(timestamps.drain(..expired).count(), 2);
+    assert_eq!(timestamps.vec, Vec::from([(30, 'c'), (40, 'd')]));
+    assert_eq!(timestamps.partition_point(|_timestamp, _value| true), 2);
+    assert_eq!(AssocList::<u8, u8>::new().partition_point(|_key, _value| true), 0);
+}
+
+#[test]
+fn get_sorted() {
+    let mut config = assoc_list!(
+        (String::from("alpha"), 1),
+        (String::from("beta"), 2),
+        (String::from("delta"), 4),
+        (String::from("gamma"), 3),
+    );
+    assert_eq!(config.get_sorted("beta"), Some(&2));
+    assert_eq!(config.get_sorted("alpha"), Some(&1));
+    assert_eq!(config.get_sorted("gamma"), Some(&3));
+    assert_eq!(config.get_sorted("epsilon"), None);
+    assert_eq!(config.get_sorted_key_value("delta"), Some((&String::from("delta"), &4)));
+    if let Some(value) = config.get_sorted_mut("delta") {
+        *value = 40;
+    }
+    assert_eq!(config.get("delta"), Some(&40));
+    assert_eq!(config.get_sorted_mut("zeta"), None);
+    assert_eq!(AssocList::<u8, u8>::new().get_sorted(&0), None, "empty AssocList");
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "AssocList is not sorted by its keys"]
+fn get_sorted_unsorted() {
+    let unsorted = assoc_list!((2, 'b'), (1, 'a'));
+    let _ = unsorted.get_sorted(&1);
+}
+
+#[test]
+fn iter_sorted() {
+    let assoc_list = assoc_list!(("c", 1), ("a", 3), ("b", 2));
+    assert_eq!(assoc_list.iter_sorted().collect::<Vec<_>>(), [(&"a", &3), (&"b", &2), (&"c", &1)]);
+    assert_eq!(assoc_list.keys_sorted().collect::<Vec<_>>(), [&"a", &"b", &"c"]);
+    assert_eq!(assoc_list.values_sorted_by_key().collect::<Vec<_>>(), [&3, &2, &1]);
+    assert_eq!(
+        assoc_list
+            .iter_sorted_by(|_key, value, _other_key, other_value| value.cmp(other_value))
+            .collect::<Vec<_>>(),
+        [(&"c", &1), (&"b", &2), (&"a", &3)]
+    );
+    assert_eq!(assoc_list.vec, Vec::from([("c", 1), ("a", 3), ("b", 2)]), "order is unchanged");
+}
+
+#[test]
+fn insert_unique_unchecked() {
+    const COUNT: usize = 1000;
+    let comparisons = Cell::new(0);
+
+    let mut checked = AssocList::with_capacity(COUNT);
+    for id in 0..COUNT {
+        let _ = checked.insert(ComparisonCounter { id, comparisons: &comparisons }, id);
+    }
+    // every key is compared with all previously inserted keys
+    let quadratic: usize = (0..COUNT).sum();
+    assert_eq!(comparisons.get(), quadratic, "insert compares with every contained key");
+
+    comparisons.set(0);
+    let mut unchecked = AssocList::with_capacity(COUNT);
+    for id in 0..COUNT {
+        let (key, value) = unchecked
+            .insert_unique_unchecked(ComparisonCounter { id, comparisons: &comparisons }, id);
+        assert_eq!((key.id, *value), (id, id));
+    }
+    let expected = if cfg!(debug_assertions) { quadratic } else { 0 };
+    assert_eq!(comparisons.get(), expected, "no comparisons without debug assertions");
+    assert_eq!(unchecked.len(), COUNT);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "AssocList already contains the key"]
+fn insert_unique_unchecked_duplicate() {
+    let mut assoc_list = assoc_list!((1, 'a'));
+    let _ = assoc_list.insert_unique_unchecked(1, 'b');
+}
+
+#[test]
+fn try_extend() {
+    let mut assoc_list = assoc_list!((1, 'a'));
+    assert_eq!(assoc_list.try_extend([(2, 'b'), (1, 'c'), (3, 'd'), (2, 'e')]), Ok(()));
+    assert_eq!(assoc_list.vec, Vec::from([(1, 'c'), (2, 'e'), (3, 'd')]), "later values are kept");
+
+    // size_hint lower bound exceeds the possible capacity
+    let mut failing = assoc_list!((0, 'z'));
+    let huge_hint = (0..usize::MAX).map(|index| (index, 'a'));
+    assert!(failing.try_extend(huge_hint).is_err());
+    assert_eq!(failing.vec, Vec::from([(0, 'z')]), "AssocList stays valid");
+}
+
+#[test]
+fn get_disjoint_mut() {
+    let mut accounts = assoc_list!(("alice", 100), ("bob", 50), ("carol", 10));
+
+    let [bob, missing, alice] = accounts.get_disjoint_mut(["bob", "dave", "alice"]);
+    assert!(missing.is_none(), "missing keys yield None");
+    let bob = bob.expect("bob is contained");
+    let alice = alice.expect("alice is contained");
+    *alice -= 30;
+    *bob += 30;
+    assert_eq!(accounts.vec, Vec::from([("alice", 70), ("bob", 80), ("carol", 10)]));
+
+    let [first, second] = accounts.get_disjoint_mut(["dave", "dave"]);
+    assert!(first.is_none() && second.is_none(), "missing keys may be requested repeatedly");
+}
+
+#[test]
+#[should_panic = "get_disjoint_mut called with overlapping keys"]
+fn get_disjoint_mut_overlapping() {
+    let mut accounts = assoc_list!(("alice", 100), ("bob", 50));
+    let _ = accounts.get_disjoint_mut(["alice", "bob", "alice"]);
+}
+
+#[test]
+fn get_cloned_copied() {
+    let names = assoc_list!((1, String::from("one")), (2, String::from("two")));
+    assert_eq!(names.get_cloned(&2), Some(String::from("two")));
+    assert_eq!(names.get_cloned(&3), None);
+
+    let numbers = assoc_list!(("one", 1), ("two", 2));
+    assert_eq!(numbers.get_copied("one"), Some(1));
+    assert_eq!(numbers.get_copied("three"), None);
+}
+
+#[test]
+fn contains_entry() {
+    let aliases = assoc_list!(("ls", String::from("list")), ("rm", String::from("remove")));
+    assert!(aliases.contains_entry("ls", "list"), "matching key and value");
+    assert!(!aliases.contains_entry("ls", "remove"), "value doesn't match");
+    assert!(!aliases.contains_entry("cp", "list"), "key is missing");
+}
+
+#[test]
+fn keys_for_value() {
+    let aliases = assoc_list!(
+        ("ls", String::from("list")),
+        ("rm", String::from("remove")),
+        ("dir", String::from("list")),
+    );
+    let list_aliases: Vec<_> = aliases.keys_for_value("list").copied().collect();
+    assert_eq!(list_aliases, Vec::from(["ls", "dir"]), "keys are yielded in insertion order");
+    assert_eq!(aliases.keys_for_value("copy").next(), None);
+
+    assert_eq!(aliases.find_key_for_value("list"), Some(&"ls"));
+    assert_eq!(aliases.find_key_for_value("remove"), Some(&"rm"));
+    assert_eq!(aliases.find_key_for_value("copy"), None);
+}
+
+#[test]
+fn get_many() {
+    let variables = assoc_list!(("name", "World"), ("greeting", "Hello"));
+    let placeholders = ["greeting", "name", "missing", "name"];
+    let resolved: Vec<_> = variables.get_many(placeholders).collect();
+    assert_eq!(
+        resolved,
+        Vec::from([Some(&"Hello"), Some(&"World"), None, Some(&"World")]),
+        "queries are neither reordered nor deduplicated"
+    );
+    assert_eq!(variables.get_many::<str, _>([]).next(), None);
+
+    let outliving: Vec<_> = {
+        let queries = Vec::from([String::from("greeting"), String::from("missing")]);
+        variables.get_many(queries.iter().map(String::as_str)).collect()
+    };
+    assert_eq!(outliving, Vec::from([Some(&"Hello"), None]), "results outlive the queries");
+}
+
+#[test]
+fn find() {
+    let mut modules = assoc_list!(("std::fmt", 1), ("core::fmt", 2), ("core::mem", 3));
+
+    let is_core = |key: &&str, _value: &i32| key.starts_with("core::");
+    assert_eq!(modules.find(is_core), Some((&"core::fmt", &2)), "first match is returned");
+    assert_eq!(modules.find(|_key, value| *value > 3), None);
+
+    let (core_key, core_value) = modules.find_mut(is_core).expect("core module is contained");
+    assert_eq!(*core_key, "core::fmt");
+    *core_value = 20;
+    assert!(modules.find_mut(|_key, value| *value > 20).is_none());
+
+    let namespace = modules.find_map(|key, value| (*value > 2).then(|| key.split("::").next()));
+    assert_eq!(namespace, Some(Some("core")));
+    assert_eq!(modules.find_map(|_key, value| (*value > 20).then_some(*value)), None);
+
+    let found = modules.find_map_mut(|key, value| key.ends_with("mem").then_some(value));
+    *found.expect("mem module is contained") = 30;
+    assert_eq!(modules.vec, Vec::from([("std::fmt", 1), ("core::fmt", 20), ("core::mem", 30)]));
+}
+
+#[test]
+fn range() {
+    use Bound::{Excluded, Included, Unbounded};
+
+    let events = assoc_list!((30, 'c'), (10, 'a'), (40, 'd'), (20, 'b'));
+    let keys_in = |range: (Bound<i32>, Bound<i32>)| -> Vec<i32> {
+        events.range(range).map(|(key, _value)| *key).collect()
+    };
+    assert_eq!(keys_in((Included(20), Included(30))), Vec::from([30, 20]), "insertion order");
+    assert_eq!(keys_in((Excluded(20), Included(30))), Vec::from([30]));
+    assert_eq!(keys_in((Included(20), Excluded(30))), Vec::from([20]));
+    assert_eq!(keys_in((Excluded(20), Excluded(30))), Vec::new());
+    assert_eq!(keys_in((Unbounded, Excluded(30))), Vec::from([10, 20]));
+    assert_eq!(keys_in((Excluded(10), Unbounded)), Vec::from([30, 40, 20]));
+    assert_eq!(keys_in((Unbounded, Unbounded)), Vec::from([30, 10, 40, 20]));
+
+    assert_eq!(events.range(25..25).next(), None, "empty range");
+    assert_eq!(keys_in((Included(35), Included(15))), Vec::new(), "inverted range");
+    assert_eq!(events.range(..=10).collect::<Vec<_>>(), Vec::from([(&10, &'a')]));
+}
+
+#[test]
+fn range_mut() {
+    use Bound::{Excluded, Included, Unbounded};
+
+    let mut counters = assoc_list!((30, 0), (10, 0), (40, 0), (20, 0));
+    let mut bump = |range: (Bound<i32>, Bound<i32>)| {
+        for (_key, value) in counters.range_mut(range) {
+            *value += 1;
+        }
+    };
+    bump((Included(20), Included(30)));
+    bump((Excluded(20), Excluded(40)));
+    bump((Unbounded, Excluded(20)));
+    bump((Excluded(10), Unbounded));
+    bump((Included(25), Excluded(25)));
+    bump((Included(35), Included(15)));
+    assert_eq!(counters.vec, Vec::from([(30, 3), (10, 1), (40, 1), (20, 2)]));
+}
+
+// required by quickcheck-macro
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn range_mut_matches_btree_map(input: Vec<(u8, u16)>, start: u8, end: u8) {
+    let mut assoc_list: AssocList<_, _> = input.iter().copied().collect();
+    let mut reference_map: BTreeMap<_, _> = input.into_iter().collect();
+    for (_key, value) in assoc_list.range_mut(start..end) {
+        *value = value.wrapping_add(1);
+    }
+    if start <= end {
+        for (_key, value) in reference_map.range_mut(start..end) {
+            *value = value.wrapping_add(1);
+        }
+    }
+    let mut actual: Vec<_> = assoc_list.vec;
+    actual.sort_unstable();
+    let expected: Vec<_> = reference_map.into_iter().collect();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn iter_prefix() {
+    let mut config = assoc_list!(
+        (String::from("net.tcp.timeout"), 30),
+        (String::from("log.level"), 2),
+        (String::from("net"), 1),
+        (String::from("net.udp"), 5),
+        (String::from("network"), 7),
+    );
+    let keys_with = |assoc_list: &AssocList<String, i32>, prefix: &str| -> Vec<String> {
+        assoc_list.iter_prefix(prefix).map(|(key, _value)| key.clone()).collect()
+    };
+    assert_eq!(keys_with(&config, "net."), Vec::from(["net.tcp.timeout", "net.udp"]));
+    assert_eq!(
+        keys_with(&config, "net"),
+        Vec::from(["net.tcp.timeout", "net", "net.udp", "network"])
+    );
+    assert_eq!(keys_with(&config, "").len(), 5, "every key starts with the empty prefix");
+    assert_eq!(keys_with(&config, "net.tcp.timeout.ms"), Vec::<String>::new(), "longer than key");
+
+    assert_eq!(config.remove_prefix("net."), 2);
+    assert_eq!(config.remove_prefix("net."), 0);
+    assert_eq!(keys_with(&config, ""), Vec::from(["log.level", "net", "network"]));
+}
+
+#[test]
+fn iter_slice_prefix() {
+    let mut packets = assoc_list!((Vec::from([0xca, 0xfe, 0x01]), 'a'), (Vec::from([0xca]), 'b'));
+    let matching: Vec<_> =
+        packets.iter_slice_prefix(&[0xca, 0xfe]).map(|(_key, value)| *value).collect();
+    assert_eq!(matching, Vec::from(['a']));
+    assert_eq!(packets.iter_slice_prefix::<u8>(&[]).count(), 2);
+
+    assert_eq!(packets.remove_slice_prefix(&[0xca, 0xfe, 0x01, 0x02]), 0, "longer than key");
+    assert_eq!(packets.remove_slice_prefix(&[0xca, 0xfe]), 1);
+    assert_eq!(packets.vec, Vec::from([(Vec::from([0xca]), 'b')]));
+    assert_eq!(packets.remove_slice_prefix::<u8>(&[]), 1, "every key starts with the empty prefix");
+    assert!(packets.is_empty());
+}
@@ -95,3 +95,19 @@ fn with_capacity_in() {
     assert_eq!(assoc_list.vec.capacity(), CAPACITY);
     assert!(unique_ord_keys(assoc_list));
 }
+
+#[test]
+fn try_with_capacity_in() {
+    const CAPACITY: usize = 3;
+    let memory = pin!([0; 1024]);
+    let test_allocator = TestAllocator::new(memory);
+    let assoc_list: Result<AssocList<u16, f32, _>, _> =
+        AssocList::try_with_capacity_in(CAPACITY, test_allocator);
+    assert!(assoc_list.is_ok_and(|list| list.vec.capacity() == CAPACITY));
+
+    let small_memory = pin!([0; 16]);
+    let small_allocator = TestAllocator::new(small_memory);
+    let too_large: Result<AssocList<u16, f32, _>, _> =
+        AssocList::try_with_capacity_in(1024, small_allocator);
+    assert!(too_large.is_err(), "allocation failure is reported");
+}
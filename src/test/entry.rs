@@ -3,7 +3,24 @@
 // signal failure in cases where `assert!` doesn't work, e.g. in a let-else.
 #![allow(clippy::panic)]
 
-use crate::{assoc_list, AssocList, Entry};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use crate::{assoc_list, entry::RawEntry, AssocList, Entry};
+
+/// Key type, where only the `id` is relevant for [`PartialEq`].
+#[derive(Debug, Clone, Copy)]
+struct TaggedKey {
+    /// The part of the key used for comparisons.
+    id: u8,
+    /// Additional data, not used for comparisons.
+    tag: &'static str,
+}
+
+impl PartialEq for TaggedKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
 
 #[test]
 fn entry() {
@@ -26,6 +43,215 @@ fn entry() {
     assert_eq!(*another_vacant_entry.or_insert("yet another value"), "yet another value");
 }
 
+#[test]
+fn entry_get() {
+    const OCCUPIED: &str = "occupied";
+    const VALUE: &str = "value";
+    const VACANT: &str = "vacant";
+    const NEW_VALUE: &str = "new value";
+    let mut assoc_list = assoc_list!((OCCUPIED, VALUE));
+
+    let mut occupied_entry = assoc_list.entry(OCCUPIED);
+    assert_eq!(occupied_entry.get(), Some(&VALUE));
+    if let Some(value) = occupied_entry.get_mut() {
+        *value = NEW_VALUE;
+    }
+    assert_eq!(*occupied_entry.or_insert(VALUE), NEW_VALUE, "entry is still usable");
+
+    let mut vacant_entry = assoc_list.entry(VACANT);
+    assert_eq!(vacant_entry.get(), None);
+    assert_eq!(vacant_entry.get_mut(), None);
+    assert_eq!(*vacant_entry.or_insert(NEW_VALUE), NEW_VALUE, "entry is still usable");
+}
+
+#[test]
+fn or_insert_with() {
+    const OCCUPIED: &str = "occupied";
+    const VALUE: &str = "value";
+    const VACANT: &str = "vacant";
+    const NEW_VALUE: &str = "new value";
+    let mut assoc_list = assoc_list!((OCCUPIED, VALUE));
+
+    let occupied_value = assoc_list
+        .entry(OCCUPIED)
+        .or_insert_with(|| panic!("default function called for an occupied entry"));
+    assert_eq!(*occupied_value, VALUE);
+
+    let vacant_value = assoc_list.entry(VACANT).or_insert_with(|| NEW_VALUE);
+    assert_eq!(*vacant_value, NEW_VALUE);
+    assert_eq!(assoc_list.get(VACANT), Some(&NEW_VALUE));
+}
+
+#[test]
+fn or_try_insert() {
+    const OCCUPIED: &str = "occupied";
+    const VALUE: &str = "value";
+    const VACANT: &str = "vacant";
+    const NEW_VALUE: &str = "new value";
+    let mut assoc_list = assoc_list!((OCCUPIED, VALUE));
+
+    assert_eq!(assoc_list.entry(OCCUPIED).or_try_insert(NEW_VALUE).map(|value| *value), Ok(VALUE));
+    assert_eq!(
+        assoc_list.entry(VACANT).or_try_insert(NEW_VALUE).map(|value| *value),
+        Ok(NEW_VALUE)
+    );
+    assert_eq!(assoc_list.vec, Vec::from([(OCCUPIED, VALUE), (VACANT, NEW_VALUE)]));
+}
+
+#[test]
+fn or_try_insert_with() {
+    const OCCUPIED: &str = "occupied";
+    const VALUE: &str = "value";
+    const VACANT: &str = "vacant";
+    const NEW_VALUE: &str = "new value";
+    let mut assoc_list = assoc_list!((OCCUPIED, VALUE));
+    let capacity = assoc_list.capacity();
+
+    let occupied_value = assoc_list
+        .entry(OCCUPIED)
+        .or_try_insert_with(|| panic!("default function called for an occupied entry"))
+        .map(|value| *value);
+    assert_eq!(occupied_value, Ok(VALUE));
+    assert_eq!(assoc_list.capacity(), capacity, "occupied entry doesn't allocate");
+
+    let vacant_value =
+        assoc_list.entry(VACANT).or_try_insert_with(|| NEW_VALUE).map(|value| *value);
+    assert_eq!(vacant_value, Ok(NEW_VALUE));
+    assert_eq!(assoc_list.vec, Vec::from([(OCCUPIED, VALUE), (VACANT, NEW_VALUE)]));
+}
+
+#[test]
+fn insert_entry() {
+    const OCCUPIED: &str = "occupied";
+    const VALUE: &str = "value";
+    const ANOTHER: &str = "another";
+    const ANOTHER_VALUE: &str = "another value";
+    const VACANT: &str = "vacant";
+    const NEW_VALUE: &str = "new value";
+    let mut assoc_list = assoc_list!((OCCUPIED, VALUE), (ANOTHER, ANOTHER_VALUE));
+
+    let occupied_entry = assoc_list.entry(OCCUPIED).insert_entry(NEW_VALUE);
+    assert_eq!(*occupied_entry.key(), OCCUPIED);
+    assert_eq!(*occupied_entry.get(), NEW_VALUE);
+    assert_eq!(assoc_list.get(OCCUPIED), Some(&NEW_VALUE));
+
+    let vacant_entry = assoc_list.entry(VACANT).insert_entry(NEW_VALUE);
+    assert_eq!(*vacant_entry.key(), VACANT);
+    assert_eq!(vacant_entry.remove_entry(), (VACANT, NEW_VALUE), "entry points to the new element");
+    assert!(!assoc_list.contains_key(VACANT));
+    assert_eq!(assoc_list.get(ANOTHER), Some(&ANOTHER_VALUE));
+}
+
+#[test]
+fn insert_entry_into_key() {
+    const OCCUPIED: &str = "occupied";
+    const VACANT: &str = "vacant";
+    let mut assoc_list = assoc_list!((OCCUPIED, 1));
+    assert_eq!(assoc_list.entry(OCCUPIED).insert_entry(2).into_key(), Some(OCCUPIED));
+    assert_eq!(assoc_list.entry(VACANT).insert_entry(3).into_key(), None, "key was moved");
+    assert_eq!(assoc_list.vec, Vec::from([(OCCUPIED, 2), (VACANT, 3)]));
+}
+
+#[test]
+fn first_entry() {
+    let mut assoc_list = assoc_list!(("oldest", 1), ("middle", 2), ("newest", 3));
+    let Some(mut first_entry) = assoc_list.first_entry() else {
+        panic!("AssocList is not empty!");
+    };
+    assert_eq!(*first_entry.key(), "oldest");
+    assert_eq!(first_entry.index(), 0);
+    assert_eq!(*first_entry.get_mut(), 1);
+    assert_eq!(first_entry.remove(), ("oldest", 1));
+    assert_eq!(assoc_list.vec, Vec::from([("middle", 2), ("newest", 3)]));
+
+    assert!(AssocList::<u8, u8>::new().first_entry().is_none(), "empty AssocList");
+}
+
+#[test]
+fn last_entry() {
+    let mut assoc_list = assoc_list!(("oldest", 1), ("middle", 2), ("newest", 3));
+    let Some(mut last_entry) = assoc_list.last_entry() else {
+        panic!("AssocList is not empty!");
+    };
+    assert_eq!(*last_entry.key(), "newest");
+    assert_eq!(last_entry.index(), 2);
+    assert_eq!(last_entry.insert(30), 3);
+    assert_eq!(last_entry.remove(), ("newest", 30));
+    assert_eq!(assoc_list.vec, Vec::from([("oldest", 1), ("middle", 2)]));
+
+    assert!(AssocList::<u8, u8>::new().last_entry().is_none(), "empty AssocList");
+}
+
+#[test]
+fn entry_by() {
+    let mut assoc_list = assoc_list!((TaggedKey { id: 1, tag: "one" }, "value"));
+
+    let occupied_entry =
+        assoc_list.entry_by(|key| key.id == 1, || panic!("key constructed for an occupied entry"));
+    let RawEntry::Occupied(occupied_entry) = occupied_entry else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(occupied_entry.key().tag, "one");
+    assert_eq!((occupied_entry.index(), *occupied_entry.get()), (0, "value"));
+
+    let vacant_entry = assoc_list.entry_by(|key| key.id == 2, || TaggedKey { id: 2, tag: "two" });
+    let RawEntry::Vacant(vacant_entry) = vacant_entry else {
+        panic!("Entry not vacant!");
+    };
+    assert_eq!(vacant_entry.key().tag, "two");
+    assert_eq!(*vacant_entry.insert("new value"), "new value");
+    assert_eq!(assoc_list.len(), 2);
+
+    let new_entry = assoc_list.entry_by(|key| key.id == 3, || TaggedKey { id: 3, tag: "three" });
+    assert_eq!(new_entry.key().tag, "three");
+    assert_eq!(*new_entry.or_insert("third"), "third");
+    let existing_entry = assoc_list.entry_by(|key| key.id == 1, || panic!("key constructed"));
+    assert_eq!(*existing_entry.or_insert_with(|| panic!("default for an occupied entry")), "value");
+    assert_eq!(assoc_list.len(), 3);
+}
+
+#[test]
+fn entry_at() {
+    let mut assoc_list = assoc_list!(("a", 1), ("b", 2), ("c", 3));
+    assert!(assoc_list.entry_at(3).is_none(), "index out of bounds");
+    let Some(mut indexed_entry) = assoc_list.entry_at(1) else {
+        panic!("Index in bounds!");
+    };
+    assert_eq!(indexed_entry.index(), 1);
+    assert_eq!(*indexed_entry.key(), "b");
+    assert_eq!(*indexed_entry.get(), 2);
+    *indexed_entry.get_mut() = 20;
+    assert_eq!(indexed_entry.insert(200), 20);
+    *indexed_entry.into_mut() += 1;
+    assert_eq!(assoc_list.vec, Vec::from([("a", 1), ("b", 201), ("c", 3)]));
+}
+
+#[test]
+fn indexed_entry_remove() {
+    let initial = assoc_list!(("a", 1), ("b", 2), ("c", 3), ("d", 4));
+    for (index, swapped, shifted) in [
+        (0, [("d", 4), ("b", 2), ("c", 3)], [("b", 2), ("c", 3), ("d", 4)]),
+        (1, [("a", 1), ("d", 4), ("c", 3)], [("a", 1), ("c", 3), ("d", 4)]),
+        (3, [("a", 1), ("b", 2), ("c", 3)], [("a", 1), ("b", 2), ("c", 3)]),
+    ] {
+        let removed = initial.vec.get(index).copied();
+
+        let mut swap_list = initial.clone();
+        let Some(swap_entry) = swap_list.entry_at(index) else {
+            panic!("Index in bounds!");
+        };
+        assert_eq!(Some(swap_entry.swap_remove()), removed);
+        assert_eq!(swap_list.vec, swapped, "swap_remove at index {index}");
+
+        let mut shift_list = initial.clone();
+        let Some(shift_entry) = shift_list.entry_at(index) else {
+            panic!("Index in bounds!");
+        };
+        assert_eq!(Some(shift_entry.remove()), removed);
+        assert_eq!(shift_list.vec, shifted, "remove at index {index}");
+    }
+}
+
 #[test]
 fn occupied_entry_key() {
     const OCCUPIED: &str = "occupied";
@@ -39,6 +265,66 @@ fn occupied_entry_key() {
     assert_eq!(*occupied_entry.key(), OCCUPIED);
 }
 
+#[test]
+fn occupied_entry_into_key() {
+    const OCCUPIED: &str = "occupied";
+    const VALUE: &str = "value";
+    const ANOTHER: &str = "another";
+    const ANOTHER_VALUE: &str = "another value";
+    let mut assoc_list =
+        assoc_list!((OCCUPIED.to_owned(), VALUE), (ANOTHER.to_owned(), ANOTHER_VALUE));
+    let assoc_list_before = assoc_list.clone();
+    let mut lookup_key = String::with_capacity(64);
+    lookup_key.push_str(OCCUPIED);
+    let lookup_key_ptr = lookup_key.as_ptr();
+    let Entry::Occupied(occupied_entry) = assoc_list.entry(lookup_key) else {
+        panic!("Entry not occupied!");
+    };
+    let key = occupied_entry.into_key().expect("Entry created with a separate key");
+    assert_eq!(key, OCCUPIED);
+    assert_eq!(key.as_ptr(), lookup_key_ptr, "the lookup key is returned");
+    assert_eq!(assoc_list.vec, assoc_list_before.vec, "the AssocList is unchanged");
+}
+
+#[test]
+fn occupied_entry_index() {
+    let mut assoc_list = assoc_list!(("first", 1), ("second", 2), ("third", 3));
+    let Entry::Occupied(second_entry) = assoc_list.entry("second") else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(second_entry.index(), 1);
+
+    let Entry::Occupied(third_entry) = assoc_list.entry("third") else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(third_entry.index(), 2);
+
+    // swap-based removal moves the last element to the removed position
+    let _ = assoc_list.swap_remove("first");
+    let Entry::Occupied(moved_entry) = assoc_list.entry("third") else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(moved_entry.index(), 0, "previously observed index is invalidated");
+}
+
+#[test]
+fn occupied_entry_get_key_value() {
+    const STORED: TaggedKey = TaggedKey { id: 1, tag: "stored" };
+    const LOOKUP: TaggedKey = TaggedKey { id: 1, tag: "lookup" };
+    let mut assoc_list = assoc_list!((STORED, "value"));
+    let Entry::Occupied(occupied_entry) = assoc_list.entry(LOOKUP) else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(occupied_entry.key().tag, LOOKUP.tag);
+    let (stored_key, value) = occupied_entry.get_key_value();
+    assert_eq!((stored_key.tag, *value), (STORED.tag, "value"));
+
+    let (owned_stored_key, mut_value) = occupied_entry.into_key_value();
+    assert_eq!(owned_stored_key.tag, STORED.tag);
+    *mut_value = "new value";
+    assert_eq!(assoc_list.vec.first().map(|(_key, new_value)| *new_value), Some("new value"));
+}
+
 #[test]
 fn occupied_entry_get() {
     const OCCUPIED: &str = "occupied";
@@ -60,12 +346,32 @@ fn occupied_entry_get_mut() {
     const ANOTHER_VALUE: &str = "another value";
     const NEW_VALUE: &str = "new value";
     let mut assoc_list = assoc_list!((OCCUPIED, VALUE), (ANOTHER, ANOTHER_VALUE));
-    let Entry::Occupied(occupied_entry) = assoc_list.entry(OCCUPIED) else {
+    let Entry::Occupied(mut occupied_entry) = assoc_list.entry(OCCUPIED) else {
         panic!("Entry not occupied!");
     };
     let value = occupied_entry.get_mut();
     assert_eq!(*value, VALUE);
     *value = NEW_VALUE;
+    assert_eq!(*occupied_entry.get(), NEW_VALUE, "entry is still usable");
+    assert_eq!(occupied_entry.remove(), NEW_VALUE);
+    assert!(!assoc_list.contains_key(OCCUPIED));
+    assert_eq!(assoc_list.get(ANOTHER), Some(&ANOTHER_VALUE));
+}
+
+#[test]
+fn occupied_entry_into_mut() {
+    const OCCUPIED: &str = "occupied";
+    const VALUE: &str = "value";
+    const ANOTHER: &str = "another";
+    const ANOTHER_VALUE: &str = "another value";
+    const NEW_VALUE: &str = "new value";
+    let mut assoc_list = assoc_list!((OCCUPIED, VALUE), (ANOTHER, ANOTHER_VALUE));
+    let Entry::Occupied(occupied_entry) = assoc_list.entry(OCCUPIED) else {
+        panic!("Entry not occupied!");
+    };
+    let value = occupied_entry.into_mut();
+    assert_eq!(*value, VALUE);
+    *value = NEW_VALUE;
     assert_eq!(assoc_list.get(OCCUPIED), Some(&NEW_VALUE));
     assert_eq!(assoc_list.get(ANOTHER), Some(&ANOTHER_VALUE));
 }
@@ -103,6 +409,73 @@ fn occupied_entry_remove() {
     assert_eq!(assoc_list.get(ANOTHER), Some(&ANOTHER_VALUE));
 }
 
+#[test]
+fn occupied_entry_swap_remove_entry() {
+    let mut swapped = assoc_list!(("a", 1), ("b", 2), ("c", 3), ("d", 4));
+    let mut shifted = swapped.clone();
+
+    let Entry::Occupied(swap_entry) = swapped.entry("b") else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(swap_entry.swap_remove_entry(), ("b", 2));
+    assert_eq!(swapped.vec, Vec::from([("a", 1), ("d", 4), ("c", 3)]), "last element moved");
+
+    let Entry::Occupied(shift_entry) = shifted.entry("b") else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(shift_entry.remove_entry(), ("b", 2));
+    assert_eq!(shifted.vec, Vec::from([("a", 1), ("c", 3), ("d", 4)]), "order is preserved");
+}
+
+#[test]
+fn occupied_entry_swap_remove() {
+    let mut swapped = assoc_list!(("a", 1), ("b", 2), ("c", 3), ("d", 4));
+    let mut shifted = swapped.clone();
+
+    let Entry::Occupied(swap_entry) = swapped.entry("a") else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(swap_entry.swap_remove(), 1);
+    assert_eq!(swapped.vec, Vec::from([("d", 4), ("b", 2), ("c", 3)]), "last element moved");
+
+    let Entry::Occupied(shift_entry) = shifted.entry("a") else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(shift_entry.remove(), 1);
+    assert_eq!(shifted.vec, Vec::from([("b", 2), ("c", 3), ("d", 4)]), "order is preserved");
+}
+
+#[test]
+fn occupied_entry_replace_key() {
+    const STORED: TaggedKey = TaggedKey { id: 1, tag: "stored" };
+    const LOOKUP: TaggedKey = TaggedKey { id: 1, tag: "lookup" };
+    const ANOTHER: TaggedKey = TaggedKey { id: 2, tag: "another" };
+    let mut assoc_list = assoc_list!((STORED, "value"), (ANOTHER, "another value"));
+    let Entry::Occupied(occupied_entry) = assoc_list.entry(LOOKUP) else {
+        panic!("Entry not occupied!");
+    };
+    let old_key = occupied_entry.replace_key().expect("Entry created with a separate key");
+    assert_eq!(old_key.tag, STORED.tag);
+    let tags: Vec<_> = assoc_list.iter().map(|(key, value)| (key.tag, *value)).collect();
+    assert_eq!(tags, Vec::from([(LOOKUP.tag, "value"), (ANOTHER.tag, "another value")]));
+}
+
+#[test]
+fn occupied_entry_replace_entry() {
+    const STORED: TaggedKey = TaggedKey { id: 1, tag: "stored" };
+    const LOOKUP: TaggedKey = TaggedKey { id: 1, tag: "lookup" };
+    const ANOTHER: TaggedKey = TaggedKey { id: 2, tag: "another" };
+    let mut assoc_list = assoc_list!((STORED, "value"), (ANOTHER, "another value"));
+    let Entry::Occupied(occupied_entry) = assoc_list.entry(LOOKUP) else {
+        panic!("Entry not occupied!");
+    };
+    let (old_key, old_value) =
+        occupied_entry.replace_entry("new value").expect("Entry created with a separate key");
+    assert_eq!((old_key.tag, old_value), (STORED.tag, "value"));
+    let tags: Vec<_> = assoc_list.iter().map(|(key, value)| (key.tag, *value)).collect();
+    assert_eq!(tags, Vec::from([(LOOKUP.tag, "new value"), (ANOTHER.tag, "another value")]));
+}
+
 #[test]
 fn occupied_entry_insert() {
     const OCCUPIED: &str = "occupied";
@@ -135,6 +508,19 @@ fn vacant_entry_key() {
     assert_eq!(*vacant_entry.key(), VACANT);
 }
 
+#[test]
+fn vacant_entry_into_key() {
+    const OCCUPIED: &str = "occupied";
+    const VALUE: &str = "value";
+    const VACANT: &str = "vacant";
+    let mut assoc_list = assoc_list!((OCCUPIED.to_owned(), VALUE));
+    let Entry::Vacant(vacant_entry) = assoc_list.entry(VACANT.to_owned()) else {
+        panic!("Entry not vacant!");
+    };
+    assert_eq!(vacant_entry.into_key(), VACANT);
+    assert_eq!(assoc_list.vec, Vec::from([(OCCUPIED.to_owned(), VALUE)]), "nothing inserted");
+}
+
 #[test]
 fn vacant_entry_insert() {
     const OCCUPIED: &str = "occupied";
@@ -153,3 +539,70 @@ fn vacant_entry_insert() {
     assert_eq!(assoc_list.get(OCCUPIED), Some(&VALUE));
     assert_eq!(assoc_list.get(ANOTHER), Some(&ANOTHER_VALUE));
 }
+
+#[test]
+fn vacant_entry_insert_entry() {
+    const OCCUPIED: &str = "occupied";
+    const VALUE: &str = "value";
+    const VACANT: &str = "vacant";
+    const NEW_VALUE: &str = "new value";
+    const ANOTHER_NEW_VALUE: &str = "another new value";
+    let mut assoc_list = assoc_list!((OCCUPIED, VALUE));
+    let Entry::Vacant(vacant_entry) = assoc_list.entry(VACANT) else {
+        panic!("Entry not vacant!");
+    };
+    let mut occupied_entry = vacant_entry.insert_entry(NEW_VALUE);
+    assert_eq!(*occupied_entry.key(), VACANT);
+    assert_eq!(occupied_entry.index(), 1);
+    assert_eq!(occupied_entry.insert(ANOTHER_NEW_VALUE), NEW_VALUE);
+    assert_eq!(assoc_list.vec, Vec::from([(OCCUPIED, VALUE), (VACANT, ANOTHER_NEW_VALUE)]));
+}
+
+#[test]
+fn vacant_entry_insert_entry_without_clone() {
+    /// Key type without a [`Clone`]-implementation.
+    #[derive(Debug, PartialEq)]
+    struct UniqueKey(u8);
+
+    let mut assoc_list: AssocList<UniqueKey, u8> = AssocList::new();
+    let Entry::Vacant(vacant_entry) = assoc_list.entry(UniqueKey(1)) else {
+        panic!("Entry not vacant!");
+    };
+    let mut occupied_entry = vacant_entry.insert_entry(2);
+    assert_eq!(*occupied_entry.key(), UniqueKey(1), "key refers to the stored key");
+    assert_eq!(occupied_entry.insert(3), 2);
+    assert_eq!(assoc_list.vec, Vec::from([(UniqueKey(1), 3)]));
+}
+
+#[test]
+fn vacant_entry_insert_entry_without_separate_key() {
+    let mut assoc_list: AssocList<&str, u8> = AssocList::new();
+    let Entry::Vacant(vacant_entry) = assoc_list.entry("vacant") else {
+        panic!("Entry not vacant!");
+    };
+    let occupied_entry = vacant_entry.insert_entry(1);
+    let Err(occupied_entry) = occupied_entry.replace_key() else {
+        panic!("replace_key without a separate key succeeded!");
+    };
+    let Err((occupied_entry, value)) = occupied_entry.replace_entry(2) else {
+        panic!("replace_entry without a separate key succeeded!");
+    };
+    assert_eq!((occupied_entry.index(), value), (0, 2), "the entry is returned unchanged");
+    assert_eq!(occupied_entry.into_key(), None);
+    assert_eq!(assoc_list.vec, Vec::from([("vacant", 1)]), "the AssocList is unchanged");
+}
+
+#[test]
+fn occupied_entry_get_cloned_copied() {
+    let mut numbers = assoc_list!(("copy", 1), ("another", 2));
+    let Entry::Occupied(number_entry) = numbers.entry("copy") else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(number_entry.get_copied(), 1);
+
+    let mut strings = assoc_list!(("clone", String::from("value")));
+    let Entry::Occupied(string_entry) = strings.entry("clone") else {
+        panic!("Entry not occupied!");
+    };
+    assert_eq!(string_entry.get_cloned(), "value");
+}
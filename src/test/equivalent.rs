@@ -0,0 +1,66 @@
+//! Unit tests for lookups with an [`Equivalent`] query type.
+
+use alloc::string::String;
+
+use crate::{assoc_list, equivalent::Equivalent, AssocList};
+
+/// Composite key, owning its name.
+#[derive(Debug, PartialEq)]
+struct Key {
+    /// The owned name.
+    name: String,
+    /// The version number.
+    version: u32,
+}
+
+/// Borrowed query for a [`Key`].
+struct KeyRef<'a> {
+    /// The borrowed name.
+    name: &'a str,
+    /// The version number.
+    version: u32,
+}
+
+impl Equivalent<Key> for KeyRef<'_> {
+    fn equivalent(&self, key: &Key) -> bool {
+        self.name == key.name && self.version == key.version
+    }
+}
+
+/// Query comparing [`String`]-keys ignoring ASCII case.
+struct CaseInsensitive<'a>(&'a str);
+
+impl Equivalent<String> for CaseInsensitive<'_> {
+    fn equivalent(&self, key: &String) -> bool {
+        self.0.eq_ignore_ascii_case(key)
+    }
+}
+
+#[test]
+fn composite_key() {
+    let mut packages = assoc_list!(
+        (Key { name: String::from("serde"), version: 1 }, "stable"),
+        (Key { name: String::from("serde"), version: 2 }, "beta"),
+    );
+    assert_eq!(packages.get(&KeyRef { name: "serde", version: 2 }), Some(&"beta"));
+    assert_eq!(packages.get(&KeyRef { name: "serde", version: 3 }), None);
+    assert!(packages.contains_key(&KeyRef { name: "serde", version: 1 }));
+    assert_eq!(packages.remove(&KeyRef { name: "serde", version: 1 }), Some("stable"));
+    assert_eq!(packages.len(), 1);
+}
+
+#[test]
+fn case_insensitive() {
+    let mut headers = assoc_list!(
+        (String::from("Content-Type"), "text/plain"),
+        (String::from("Content-Length"), "42"),
+    );
+    assert_eq!(headers.get(&CaseInsensitive("content-type")), Some(&"text/plain"));
+    if let Some(length) = headers.get_mut(&CaseInsensitive("CONTENT-LENGTH")) {
+        *length = "43";
+    }
+    assert_eq!(headers.get(&CaseInsensitive("Content-length")), Some(&"43"));
+    // Borrow-based lookups keep working through the blanket implementation
+    assert_eq!(headers.get("Content-Length"), Some(&"43"));
+    assert_eq!(headers.get("content-length"), None);
+}
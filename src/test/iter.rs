@@ -7,7 +7,7 @@ use alloc::{
 
 use quickcheck_macros::quickcheck;
 
-use crate::{test::split_tuple_refs, AssocList};
+use crate::{assoc_list, test::split_tuple_refs, AssocList};
 
 #[test]
 fn keys() {
@@ -133,13 +133,31 @@ fn iter_mut(input: Vec<(i64, i32)>) {
 fn drain(input: Vec<(i16, u8)>) {
     let mut assoc_list: AssocList<_, _> = input.iter().copied().collect();
     let reference_map: BTreeMap<_, _> = input.into_iter().collect();
-    let actual_values: BTreeMap<_, _> = assoc_list.drain().collect();
+    let actual_values: BTreeMap<_, _> = assoc_list.drain(..).collect();
 
     assert_eq!(actual_values, reference_map, "drain returns the correct elements");
 
     assert!(assoc_list.is_empty(), "drain removes all elements from the AssocList");
 }
 
+#[test]
+fn drain_range() {
+    let mut log = assoc_list!((1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e'));
+    assert_eq!(log.drain(2..2).count(), 0, "empty range");
+    assert_eq!(log.len(), 5);
+
+    assert_eq!(log.drain(..2).collect::<Vec<_>>(), Vec::from([(1, 'a'), (2, 'b')]));
+    assert_eq!(log.vec, Vec::from([(3, 'c'), (4, 'd'), (5, 'e')]), "remaining order");
+
+    let mut drain = log.drain(1..);
+    assert_eq!(drain.next(), Some((4, 'd')));
+    drop(drain);
+    assert_eq!(log.vec, Vec::from([(3, 'c')]), "dropping the iterator removes the range");
+
+    assert_eq!(log.drain(..).collect::<Vec<_>>(), Vec::from([(3, 'c')]), "full range");
+    assert!(log.is_empty());
+}
+
 #[quickcheck]
 fn into_iter(input: Vec<(u8, i32)>) {
     let assoc_list: AssocList<_, _> = input.iter().copied().collect();